@@ -0,0 +1,10 @@
+// Pseudorandom data for the benchmarks, so that every run measures the same input
+pub fn random_data(len: usize) -> Vec<u32> {
+    let mut state = 0x2545F4914F6CDD1Du64;
+    (0..len).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u32
+    }).collect()
+}
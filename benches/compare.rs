@@ -0,0 +1,135 @@
+// Compares MaxValues with the alternatives from the standard library:
+// BinaryHeap with Reverse, sort + truncate and select_nth_unstable.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion};
+use criterion::measurement::WallTime;
+use max_values::MaxValues;
+use common::random_data;
+
+mod common;
+
+#[derive(Clone, Copy)]
+enum Distribution {
+    Random,
+    Ascending,
+    Descending,
+    Duplicates,
+}
+
+impl Distribution {
+    fn name(self) -> &'static str {
+        match self {
+            Distribution::Random => "random",
+            Distribution::Ascending => "ascending",
+            Distribution::Descending => "descending",
+            Distribution::Duplicates => "duplicates",
+        }
+    }
+
+    fn generate(self, len: usize) -> Vec<u32> {
+        match self {
+            Distribution::Random => random_data(len),
+            Distribution::Ascending => (0..len as u32).collect(),
+            Distribution::Descending => (0..len as u32).rev().collect(),
+            Distribution::Duplicates => random_data(len).into_iter().map(|x| x % 16).collect(),
+        }
+    }
+}
+
+// Large element, which is expensive to move
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Large {
+    key: u32,
+    payload: [u64; 16],
+}
+
+fn max_values<T: Ord, const N: usize>(data: Vec<T>) -> MaxValues<T, N> {
+    data.into_iter().collect()
+}
+
+fn binary_heap<T: Ord>(data: Vec<T>, n: usize) -> BinaryHeap<Reverse<T>> {
+    let mut heap = BinaryHeap::with_capacity(n);
+    for x in data {
+        if heap.len() < n {
+            heap.push(Reverse(x));
+        } else if let Some(mut min) = heap.peek_mut() {
+            if min.0 < x {
+                *min = Reverse(x);
+            }
+        }
+    }
+    heap
+}
+
+fn sort_truncate<T: Ord>(mut data: Vec<T>, n: usize) -> Vec<T> {
+    data.sort_unstable_by(|a, b| b.cmp(a));
+    data.truncate(n);
+    data
+}
+
+fn select_nth<T: Ord>(mut data: Vec<T>, n: usize) -> Vec<T> {
+    if n > 0 && n < data.len() {
+        data.select_nth_unstable_by(n - 1, |a, b| b.cmp(a));
+        data.truncate(n);
+    }
+    data
+}
+
+fn bench_n<T: Ord + Clone, const N: usize>(group: &mut BenchmarkGroup<WallTime>, data: &[T]) {
+    group.bench_with_input(BenchmarkId::new("max_values", N), data, |b, data| {
+        b.iter_batched(|| data.to_vec(), |data| black_box(max_values::<T, N>(data)), BatchSize::LargeInput)
+    });
+    group.bench_with_input(BenchmarkId::new("max_values_push_slice", N), data, |b, data| {
+        b.iter(|| {
+            let mut values = MaxValues::<T, N>::new();
+            values.push_slice(data);
+            black_box(values)
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("binary_heap", N), data, |b, data| {
+        b.iter_batched(|| data.to_vec(), |data| black_box(binary_heap(data, N)), BatchSize::LargeInput)
+    });
+    group.bench_with_input(BenchmarkId::new("sort_truncate", N), data, |b, data| {
+        b.iter_batched(|| data.to_vec(), |data| black_box(sort_truncate(data, N)), BatchSize::LargeInput)
+    });
+    group.bench_with_input(BenchmarkId::new("select_nth_unstable", N), data, |b, data| {
+        b.iter_batched(|| data.to_vec(), |data| black_box(select_nth(data, N)), BatchSize::LargeInput)
+    });
+}
+
+fn bench_all_n<T: Ord + Clone>(c: &mut Criterion, name: &str, data: &[T]) {
+    let mut group = c.benchmark_group(name);
+    bench_n::<T, 1>(&mut group, data);
+    bench_n::<T, 8>(&mut group, data);
+    bench_n::<T, 64>(&mut group, data);
+    bench_n::<T, 1024>(&mut group, data);
+    group.finish();
+}
+
+fn bench_u32(c: &mut Criterion) {
+    let distributions = [Distribution::Random, Distribution::Ascending, Distribution::Descending, Distribution::Duplicates];
+    for len in [1_000, 100_000] {
+        for distribution in distributions {
+            let data = distribution.generate(len);
+            bench_all_n(c, &format!("u32/{}/{}", distribution.name(), len), &data);
+        }
+    }
+}
+
+fn bench_string(c: &mut Criterion) {
+    let data: Vec<String> = Distribution::Random.generate(100_000).into_iter()
+        .map(|x| format!("{:010}", x))
+        .collect();
+    bench_all_n(c, "string/random/100000", &data);
+}
+
+fn bench_large(c: &mut Criterion) {
+    let data: Vec<Large> = Distribution::Random.generate(100_000).into_iter()
+        .map(|key| Large { key, payload: [key as u64; 16] })
+        .collect();
+    bench_all_n(c, "large/random/100000", &data);
+}
+
+criterion_group!(benches, bench_u32, bench_string, bench_large);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+// Compares quickselect and the heap, which are used by `top_n_in_place` and `MaxValues::from_slice`,
+// for different ratios of n to the length of the slice.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use max_values::{top_n_in_place, MaxValues};
+use common::random_data;
+
+mod common;
+
+fn select(values: &mut [u32], n: usize) {
+    values.select_nth_unstable_by(n - 1, |a, b| b.cmp(a));
+}
+
+// The heap with chunked rejection of small values, which is used by `MaxValues::from_slice` and `top_n_in_place`
+fn heap<const N: usize>(values: &[u32]) -> MaxValues<u32, N> {
+    let mut result = MaxValues::new();
+    result.push_slice(values);
+    result
+}
+
+macro_rules! bench_select_n {
+    ($group:expr, $data:expr, $n:expr) => {
+        $group.bench_with_input(BenchmarkId::new("select_nth_unstable", $n), &$n, |b, &n| {
+            b.iter_batched_ref(|| $data.clone(), |values| select(values, n), criterion::BatchSize::LargeInput)
+        });
+        $group.bench_with_input(BenchmarkId::new("top_n_in_place", $n), &$n, |b, &n| {
+            b.iter_batched_ref(|| $data.clone(), |values| black_box(top_n_in_place(values, n).len()), criterion::BatchSize::LargeInput)
+        });
+    };
+}
+
+macro_rules! bench_n {
+    ($group:expr, $data:expr, $($n:literal),*) => {
+        $(
+            bench_select_n!($group, $data, $n);
+            $group.bench_with_input(BenchmarkId::new("heap", $n), &$n, |b, _| {
+                b.iter_batched_ref(|| $data.clone(), |values| black_box(heap::<$n>(values)), criterion::BatchSize::LargeInput)
+            });
+        )*
+    };
+}
+
+fn bench_select(c: &mut Criterion) {
+    let data = random_data(1_000);
+    let mut group = c.benchmark_group("select_1000");
+    bench_n!(group, data, 1, 4, 16, 32, 64, 256);
+    group.finish();
+
+    let data = random_data(100_000);
+    let mut group = c.benchmark_group("select_100000");
+    bench_n!(group, data, 1, 16, 256, 512, 1024, 4096);
+    group.finish();
+
+    let data = random_data(10_000_000);
+    let mut group = c.benchmark_group("select_10000000");
+    group.sample_size(10);
+    // The heap for such n is too big for the stack, so only quickselect and `top_n_in_place` are compared
+    for n in [1024, 16384, 32768, 65536] {
+        bench_select_n!(group, data, n);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_select);
+criterion_main!(benches);
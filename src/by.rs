@@ -0,0 +1,184 @@
+// Variants of MaxValues, which compare elements with custom function instead of Ord
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::slice::Iter;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::{push, sort_heap};
+
+
+/// Struct for getting max values out of given, compared by given function
+#[derive(Debug, Clone)]
+pub struct MaxValuesBy<T, F: Fn(&T, &T) -> Ordering, const N: usize> {
+    data: ArrayVec<T, N>,
+    compare: F
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering, const N: usize> MaxValuesBy<T, F, N> {
+    /// Creates new empty [`MaxValuesBy`] data structure, which compares elements with `compare`
+    pub fn new(compare: F) -> Self {
+        MaxValuesBy { data: ArrayVec::new(), compare }
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is bigger than one of the elements according to the comparison function.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        push(&mut self.data, value, &self.compare);
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxValuesBy.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxValuesBy.
+    pub fn as_values(&self) -> &ArrayVec<T, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order according to the comparison function (the biggest value goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        sort_heap(&mut data, &self.compare);
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order according to the comparison function (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering, const N: usize> AsRef<[T]> for MaxValuesBy<T, F, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering, const N: usize> IntoIterator for MaxValuesBy<T, F, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        self.data.into_iter()
+    }
+}
+
+
+/// Struct for getting max values out of given, compared by the key, extracted with given function
+#[derive(Debug, Clone)]
+pub struct MaxValuesByKey<T, K: Ord, F: Fn(&T) -> K, const N: usize> {
+    data: ArrayVec<T, N>,
+    key: F,
+    _key: PhantomData<fn() -> K>
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K, const N: usize> MaxValuesByKey<T, K, F, N> {
+    /// Creates new empty [`MaxValuesByKey`] data structure, which compares elements by `key`
+    pub fn new(key: F) -> Self {
+        MaxValuesByKey { data: ArrayVec::new(), key, _key: PhantomData }
+    }
+
+    /// Pushes an element into the data structure,
+    /// if its key is bigger than the key of one of the elements.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        let key = &self.key;
+        push(&mut self.data, value, |a: &T, b: &T| key(a).cmp(&key(b)));
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxValuesByKey.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxValuesByKey.
+    pub fn as_values(&self) -> &ArrayVec<T, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order of their keys (the biggest key goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        let key = &self.key;
+        sort_heap(&mut data, |a: &T, b: &T| key(a).cmp(&key(b)));
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order of their keys (the biggest key goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K, const N: usize> AsRef<[T]> for MaxValuesByKey<T, K, F, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K, const N: usize> IntoIterator for MaxValuesByKey<T, K, F, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MaxValuesBy, MaxValuesByKey, MaxValuesIterExt};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_max_values_by() {
+        let mut values = MaxValuesBy::<f64, _, 2>::new(|a: &f64, b: &f64| a.total_cmp(b));
+        for x in [0.5, 2.5, -1.0, 1.5] {
+            values.push(x);
+        }
+        let mut result = values.to_values();
+        result.sort_by(f64::total_cmp);
+        assert_eq!(result.as_slice(), [1.5, 2.5]);
+    }
+
+    #[test]
+    fn test_max_values_by_key() {
+        let mut values = MaxValuesByKey::<(&str, u32), _, _, 3>::new(|row: &(&str, u32)| row.1);
+        for row in [("a", 3), ("b", 1), ("c", 7), ("d", 5), ("e", 2)] {
+            values.push(row);
+        }
+        assert_eq!(values.iter().map(|row| row.0).collect::<HashSet<_>>(), HashSet::from(["a", "c", "d"]));
+    }
+
+    #[test]
+    fn test_iterator() {
+        let values: [i32; 9] = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+        assert_eq!(
+            values.into_iter().max_values_by::<3>(|a, b| b.cmp(a)).collect::<HashSet<_>>(),
+            HashSet::from([0, 1, 2])
+        );
+        assert_eq!(
+            values.into_iter().max_values_by_key::<3, _>(|x| (x - 6).abs()).collect::<HashSet<_>>(),
+            HashSet::from([0, 1, 15])
+        );
+    }
+
+    #[test]
+    fn test_sorted_iterator() {
+        let values: [i32; 9] = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+        assert_eq!(values.into_iter().max_values_sorted_by::<4>(|a, b| b.cmp(a)).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(values.into_iter().max_values_sorted_by_key::<3, _>(|x| (x - 6).abs()).collect::<Vec<_>>(), [15, 0, 1]);
+    }
+}
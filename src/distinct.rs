@@ -0,0 +1,236 @@
+// Variants of MaxValues, which don't keep equal values
+use core::marker::PhantomData;
+use core::mem;
+use core::slice::Iter;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::{push, sort_heap, update};
+use crate::Pushed;
+
+
+/// Struct for getting distinct max values out of given. The value, which is equal to one of the values, is rejected.
+#[derive(Debug, Clone)]
+pub struct MaxDistinctValues<T: Ord, const N: usize> {
+    data: ArrayVec<T, N>
+}
+
+impl<T: Ord, const N: usize> MaxDistinctValues<T, N> {
+    /// Creates new empty [`MaxDistinctValues`] data structure
+    pub fn new() -> Self {
+        MaxDistinctValues { data: ArrayVec::new() }
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is bigger than one of the elements and isn't equal to any of them.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        self.push_report(value);
+    }
+
+    /// Same as [`MaxDistinctValues::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the smallest element or is rejected.
+    pub fn push_report(&mut self, value: T) -> Pushed<T> {
+        // Values, which are too small, are rejected before looking for the equal value
+        let too_small = self.data.is_full() && self.data.first().is_none_or(|min| min >= &value);
+        if too_small || self.data.contains(&value) {
+            return Pushed::Rejected(value);
+        }
+        push(&mut self.data, value, T::cmp)
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N distinct values are pushed to MaxDistinctValues.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N distinct values are pushed to MaxDistinctValues.
+    pub fn as_values(&self) -> &ArrayVec<T, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        sort_heap(&mut data, T::cmp);
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<T: Ord, const N: usize> Default for MaxDistinctValues<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for MaxDistinctValues<T, N> {
+    fn from_iter<IterT: IntoIterator<Item = T>>(iter: IterT) -> Self {
+        let mut values = MaxDistinctValues::new();
+        iter.into_iter().for_each(|x| values.push(x));
+        values
+    }
+}
+
+impl<T: Ord, const N: usize> IntoIterator for MaxDistinctValues<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        self.data.into_iter()
+    }
+}
+
+
+/// Struct for getting max values out of given, which have distinct keys, extracted with given function.
+/// Unlike [`MaxValuesByKey`](crate::MaxValuesByKey), the values are still compared with [`Ord`], while keys are used only for deduplication,
+/// e.g. the values may be ranked by score and deduplicated by id. Of the values with the same key only the biggest one is kept.
+#[derive(Debug, Clone)]
+pub struct MaxDistinctValuesByKey<T: Ord, K: Eq, F: Fn(&T) -> K, const N: usize> {
+    data: ArrayVec<T, N>,
+    key: F,
+    _key: PhantomData<fn() -> K>
+}
+
+impl<T: Ord, K: Eq, F: Fn(&T) -> K, const N: usize> MaxDistinctValuesByKey<T, K, F, N> {
+    /// Creates new empty [`MaxDistinctValuesByKey`] data structure, which deduplicates values by `key`
+    pub fn new(key: F) -> Self {
+        MaxDistinctValuesByKey { data: ArrayVec::new(), key, _key: PhantomData }
+    }
+
+    /// Pushes an element into the data structure, if it is bigger than one of the elements.
+    /// If there is an element with the same key, the bigger of them is kept.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        self.push_report(value);
+    }
+
+    /// Same as [`MaxDistinctValuesByKey::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the element with the same key or the smallest element, or is rejected.
+    pub fn push_report(&mut self, value: T) -> Pushed<T> {
+        let key = (self.key)(&value);
+        match self.data.iter().position(|x| (self.key)(x) == key) {
+            Some(index) if self.data[index] < value => {
+                let old = mem::replace(&mut self.data[index], value);
+                update(&mut self.data, index, T::cmp);
+                Pushed::Replaced(old)
+            }
+            Some(_) => Pushed::Rejected(value),
+            None => push(&mut self.data, value, T::cmp),
+        }
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N distinct keys are pushed to MaxDistinctValuesByKey.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N distinct keys are pushed to MaxDistinctValuesByKey.
+    pub fn as_values(&self) -> &ArrayVec<T, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        sort_heap(&mut data, T::cmp);
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<T: Ord, K: Eq, F: Fn(&T) -> K, const N: usize> IntoIterator for MaxDistinctValuesByKey<T, K, F, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MaxDistinctValues, MaxDistinctValuesByKey, MaxValuesIterExt, Pushed};
+
+    #[test]
+    fn test_distinct_values() {
+        let mut values = MaxDistinctValues::<i32, 3>::new();
+        values.push(2);
+        values.push(3);
+        assert_eq!(values.push_report(3), Pushed::Rejected(3));
+        values.push(5);
+        values.push(4);
+        assert_eq!(values.push_report(4), Pushed::Rejected(4));
+        assert_eq!(values.push_report(3), Pushed::Rejected(3));
+        assert_eq!(values.into_sorted_array().as_slice(), [5, 4, 3]);
+    }
+
+    #[test]
+    fn test_distinct_values_by_key() {
+        check_by_key::<2>();
+        check_by_key::<16>();
+    }
+
+    // Pushes (score, id) pairs, ranked by score and deduplicated by id
+    fn check_by_key<const N: usize>() {
+        let mut values = MaxDistinctValuesByKey::<(u32, char), _, _, N>::new(|x: &(u32, char)| x.1);
+        for x in [(5, 'a'), (3, 'b'), (8, 'a'), (4, 'c'), (2, 'a'), (9, 'b')] {
+            values.push(x);
+        }
+        let sorted = values.into_sorted_array();
+        assert_eq!(sorted[..2], [(9, 'b'), (8, 'a')]);
+        assert_eq!(sorted.len(), N.min(3));
+    }
+
+    #[test]
+    fn test_iterator() {
+        let prices = [10, 30, 20, 30, 30, 10, 25];
+        assert_eq!(prices.into_iter().max_distinct_values::<3>().collect::<Vec<_>>().len(), 3);
+        let mut top = prices.into_iter().max_distinct_values::<3>().collect::<Vec<_>>();
+        top.sort();
+        assert_eq!(top, [20, 25, 30]);
+
+        let mut top = [(5, 'a'), (3, 'b'), (8, 'a'), (4, 'c')].into_iter()
+            .max_distinct_values_by_key::<2, _>(|x| x.1)
+            .collect::<Vec<_>>();
+        top.sort();
+        assert_eq!(top, [(4, 'c'), (8, 'a')]);
+    }
+}
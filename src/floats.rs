@@ -0,0 +1,273 @@
+// Variant of MaxValues for floating point numbers, which don't implement Ord
+use core::cmp::Ordering;
+use core::slice::Iter;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::{any_accepted_by, push, sort_heap, CHUNK_SIZE};
+use crate::Pushed;
+
+
+/// Floating point number, which can be pushed to [`MaxFloats`]. Implemented for [`f32`] and [`f64`].
+pub trait Float: Copy + PartialOrd {
+    /// Total ordering of the numbers, see [`f64::total_cmp`].
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns `true` if the number is NaN.
+    fn is_nan(self) -> bool;
+}
+
+impl Float for f32 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+}
+
+impl Float for f64 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+}
+
+/// Defines how [`MaxFloats`] treats NaN values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// NaN values are never pushed.
+    #[default]
+    Ignore,
+    /// NaN values are bigger than any other value, including infinity.
+    Largest,
+    /// NaN values are smaller than any other value, including negative infinity.
+    Smallest,
+}
+
+impl NanPolicy {
+    // Comparison of the numbers, which follows the policy. Other numbers are compared with `total_cmp`,
+    // so that negative zero is smaller than positive zero.
+    fn compare<F: Float>(self, a: &F, b: &F) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => a.total_cmp(b),
+            (true, true) => Ordering::Equal,
+            (true, false) if self == NanPolicy::Smallest => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if self == NanPolicy::Smallest => Ordering::Greater,
+            (false, true) => Ordering::Less,
+        }
+    }
+}
+
+
+/// Struct for getting max values out of given floating point numbers, with given [`NanPolicy`]
+#[derive(Debug, Clone)]
+pub struct MaxFloats<F: Float, const N: usize> {
+    data: ArrayVec<F, N>,
+    policy: NanPolicy
+}
+
+impl<F: Float, const N: usize> MaxFloats<F, N> {
+    /// Creates new empty [`MaxFloats`] data structure, which ignores NaN values
+    pub fn new() -> Self {
+        Self::with_policy(NanPolicy::Ignore)
+    }
+
+    /// Creates new empty [`MaxFloats`] data structure, which treats NaN values according to `policy`
+    pub fn with_policy(policy: NanPolicy) -> Self {
+        MaxFloats { data: ArrayVec::new(), policy }
+    }
+
+    /// Returns the policy, which is used for NaN values.
+    pub fn policy(&self) -> NanPolicy {
+        self.policy
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is bigger than one of the elements.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: F) {
+        self.push_report(value);
+    }
+
+    /// Same as [`MaxFloats::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the smallest element or is rejected.
+    pub fn push_report(&mut self, value: F) -> Pushed<F> {
+        if self.policy == NanPolicy::Ignore && value.is_nan() {
+            return Pushed::Rejected(value);
+        }
+        let policy = self.policy;
+        push(&mut self.data, value, |a: &F, b: &F| policy.compare(a, b))
+    }
+
+    /// Pushes all the elements of given slice into the data structure.
+    ///
+    /// Same as [`MaxValues::push_slice`](crate::MaxValues::push_slice): once the data structure is full, every chunk of the slice
+    /// is compared with the smallest value at once, and only the chunks with values, that are big enough, are pushed one by one.
+    #[doc(alias = "extend_from_slice")]
+    pub fn push_slice(&mut self, values: &[F]) {
+        for chunk in values.chunks(CHUNK_SIZE) {
+            // The check may let through the values, which are rejected afterwards, e.g. negative zero, but never rejects the ones,
+            // which would be accepted
+            let accepted = match self.data.first() {
+                Some(&min) if self.data.is_full() && !min.is_nan() => match self.policy {
+                    NanPolicy::Largest => any_accepted_by(chunk, |&x| (x >= min) | x.is_nan()),
+                    NanPolicy::Ignore | NanPolicy::Smallest => any_accepted_by(chunk, |&x| x >= min),
+                },
+                _ => true,
+            };
+            if accepted {
+                chunk.iter().for_each(|&x| self.push(x));
+            }
+        }
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxFloats.
+    pub fn to_values(self) -> ArrayVec<F, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N values are pushed to MaxFloats.
+    pub fn as_values(&self) -> &ArrayVec<F, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, F> {
+        self.data.iter()
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the smallest of the values, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<F> {
+        self.data.first().copied()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<F, N> {
+        let mut data = self.data;
+        let policy = self.policy;
+        sort_heap(&mut data, |a: &F, b: &F| policy.compare(a, b));
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<F, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<F: Float, const N: usize> Default for MaxFloats<F, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Float, const N: usize> AsRef<[F]> for MaxFloats<F, N> {
+    fn as_ref(&self) -> &[F] {
+        &self.data
+    }
+}
+
+impl<F: Float, const N: usize> FromIterator<F> for MaxFloats<F, N> {
+    fn from_iter<IterT: IntoIterator<Item = F>>(iter: IterT) -> Self {
+        let mut values = MaxFloats::new();
+        iter.into_iter().for_each(|x| values.push(x));
+        values
+    }
+}
+
+impl<F: Float, const N: usize> Extend<F> for MaxFloats<F, N> {
+    fn extend<IterT: IntoIterator<Item = F>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl<F: Float, const N: usize> IntoIterator for MaxFloats<F, N> {
+    type IntoIter = IntoIter<F, N>;
+    type Item = F;
+
+    fn into_iter(self) -> IntoIter<F, N> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MaxFloats, MaxValuesIterExt, NanPolicy, Pushed};
+
+    const INPUT: [f64; 7] = [1.5, f64::NAN, -2.0, f64::INFINITY, 0.25, f64::NAN, 3.0];
+
+    #[test]
+    fn test_ignore() {
+        let mut values = MaxFloats::<f64, 3>::new();
+        assert!(matches!(values.push_report(f64::NAN), Pushed::Rejected(x) if x.is_nan()));
+        values.extend(INPUT);
+        assert_eq!(values.into_sorted_array().as_slice(), [f64::INFINITY, 3.0, 1.5]);
+    }
+
+    #[test]
+    fn test_largest() {
+        let mut values = MaxFloats::<f64, 3>::with_policy(NanPolicy::Largest);
+        values.extend(INPUT);
+        let sorted = values.into_sorted_array();
+        assert!(sorted[0].is_nan() && sorted[1].is_nan());
+        assert_eq!(sorted[2], f64::INFINITY);
+    }
+
+    #[test]
+    fn test_smallest() {
+        let mut values = MaxFloats::<f32, 7>::with_policy(NanPolicy::Smallest);
+        values.extend([1.5, f32::NAN, -f32::NAN, f32::NEG_INFINITY]);
+        let sorted = values.into_sorted_array();
+        assert_eq!(sorted[..2], [1.5, f32::NEG_INFINITY]);
+        assert!(sorted[2].is_nan() && sorted[3].is_nan());
+    }
+
+    #[test]
+    fn test_push_slice() {
+        let input = (0..500u32)
+            .map(|x| match x.wrapping_mul(2654435761) % 100 {
+                0 => f32::NAN,
+                1 => -0.0,
+                y => y as f32 - 50.0,
+            })
+            .collect::<Vec<_>>();
+        for policy in [NanPolicy::Ignore, NanPolicy::Largest, NanPolicy::Smallest] {
+            let mut expected = MaxFloats::<f32, 10>::with_policy(policy);
+            expected.extend(input.iter().copied());
+            let mut values = MaxFloats::<f32, 10>::with_policy(policy);
+            values.push_slice(&input[..7]);
+            values.push_slice(&input[7..]);
+            assert_eq!(
+                values.into_sorted_iter().map(f32::to_bits).collect::<Vec<_>>(),
+                expected.into_sorted_iter().map(f32::to_bits).collect::<Vec<_>>()
+            );
+        }
+
+        let mut values = MaxFloats::<f64, 2>::new();
+        values.push_slice(&[f64::NAN, f64::NAN, 1.0, f64::NAN, -1.0, 0.5]);
+        assert_eq!(values.into_sorted_array().as_slice(), [1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_iterator() {
+        let mut values = INPUT.into_iter().max_floats::<2>().collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+        assert_eq!(values, [3.0, f64::INFINITY]);
+        assert!(INPUT.into_iter().max_floats_with_policy::<1>(NanPolicy::Largest).all(f64::is_nan));
+    }
+}
@@ -0,0 +1,146 @@
+// MaxValues for every group of the values
+use core::borrow::Borrow;
+use alloc::collections::btree_map::{self, BTreeMap, Entry};
+use crate::MaxValues;
+
+
+/// Struct for getting max values of every group out of given. Every group is identified by the key,
+/// and has its own [`MaxValues`].
+#[derive(Debug, Clone)]
+pub struct GroupedMaxValues<K: Ord, T: Ord, const N: usize> {
+    groups: BTreeMap<K, MaxValues<T, N>>
+}
+
+impl<K: Ord, T: Ord, const N: usize> GroupedMaxValues<K, T, N> {
+    /// Creates new empty [`GroupedMaxValues`] data structure
+    pub fn new() -> Self {
+        GroupedMaxValues { groups: BTreeMap::new() }
+    }
+
+    /// Pushes an element into the group with given key. Creates the group, if there is no such group.
+    pub fn push(&mut self, key: K, value: T) {
+        self.groups.entry(key).or_default().push(value);
+    }
+
+    /// Returns reference to the max values of the group with given key, or `None` if nothing is pushed to the group.
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&MaxValues<T, N>> where K: Borrow<Q> {
+        self.groups.get(key)
+    }
+
+    /// Removes the group with given key and returns its max values.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<MaxValues<T, N>> where K: Borrow<Q> {
+        self.groups.remove(key)
+    }
+
+    /// Returns an iterator over the groups of self in ascending order of their keys.
+    pub fn iter(&self) -> btree_map::Iter<'_, K, MaxValues<T, N>> {
+        self.groups.iter()
+    }
+
+    /// Returns an iterator over the keys of the groups in ascending order.
+    pub fn keys(&self) -> btree_map::Keys<'_, K, MaxValues<T, N>> {
+        self.groups.keys()
+    }
+
+    /// Returns the number of groups in self.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Merges every group of `other` with the group of self with the same key,
+    /// so that self contains max values of both for every group.
+    pub fn merge(&mut self, other: GroupedMaxValues<K, T, N>) {
+        for (key, values) in other.groups {
+            match self.groups.entry(key) {
+                Entry::Occupied(mut entry) => entry.get_mut().merge(values),
+                Entry::Vacant(entry) => {
+                    entry.insert(values);
+                }
+            }
+        }
+    }
+}
+
+impl<K: Ord, T: Ord, const N: usize> Default for GroupedMaxValues<K, T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, T: Ord, const N: usize> FromIterator<(K, T)> for GroupedMaxValues<K, T, N> {
+    fn from_iter<IterT: IntoIterator<Item = (K, T)>>(iter: IterT) -> Self {
+        let mut values = GroupedMaxValues::new();
+        iter.into_iter().for_each(|(key, value)| values.push(key, value));
+        values
+    }
+}
+
+impl<K: Ord, T: Ord, const N: usize> Extend<(K, T)> for GroupedMaxValues<K, T, N> {
+    fn extend<IterT: IntoIterator<Item = (K, T)>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|(key, value)| self.push(key, value));
+    }
+}
+
+impl<K: Ord, T: Ord, const N: usize> IntoIterator for GroupedMaxValues<K, T, N> {
+    type IntoIter = btree_map::IntoIter<K, MaxValues<T, N>>;
+    type Item = (K, MaxValues<T, N>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups.into_iter()
+    }
+}
+
+impl<'a, K: Ord, T: Ord, const N: usize> IntoIterator for &'a GroupedMaxValues<K, T, N> {
+    type IntoIter = btree_map::Iter<'a, K, MaxValues<T, N>>;
+    type Item = (&'a K, &'a MaxValues<T, N>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupedMaxValues, MaxValuesIterExt};
+
+    const PRODUCTS: [(&str, &str, u32); 8] = [
+        ("fruit", "apple", 30), ("fruit", "pear", 20), ("tools", "saw", 15), ("fruit", "plum", 45),
+        ("tools", "hammer", 25), ("fruit", "kiwi", 10), ("books", "atlas", 5), ("tools", "drill", 70),
+    ];
+
+    #[test]
+    fn test_grouped() {
+        let mut values = GroupedMaxValues::<&str, (u32, &str), 2>::new();
+        for (category, name, sales) in PRODUCTS {
+            values.push(category, (sales, name));
+        }
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.keys().copied().collect::<Vec<_>>(), ["books", "fruit", "tools"]);
+        assert_eq!(values.get("fruit").unwrap().clone().into_sorted_array().as_slice(), [(45, "plum"), (30, "apple")]);
+        assert_eq!(values.get("books").unwrap().len(), 1);
+        assert!(values.get("toys").is_none());
+    }
+
+    #[test]
+    fn test_merge() {
+        let (first, second) = PRODUCTS.split_at(4);
+        let mut values: GroupedMaxValues<&str, u32, 2> = first.iter().map(|x| (x.0, x.2)).collect();
+        values.merge(second.iter().map(|x| (x.0, x.2)).collect());
+        let top = values.into_iter()
+            .map(|(category, values)| (category, values.into_sorted_array().to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(top, [("books", vec![5]), ("fruit", vec![45, 30]), ("tools", vec![70, 25])]);
+    }
+
+    #[test]
+    fn test_iterator() {
+        let groups = PRODUCTS.into_iter().max_values_per_group::<1, _>(|product| product.0);
+        let top = groups.iter().map(|(_, values)| values.as_values()[0].1).collect::<Vec<_>>();
+        assert_eq!(top, ["atlas", "plum", "saw"]);
+    }
+}
@@ -1,13 +1,113 @@
-use crate::MaxValues;
+use core::cmp::Ordering;
+use crate::{Float, MaxDistinctValues, MaxDistinctValuesByKey, MaxFloats, MaxValues, MaxValuesBy, MaxValuesByKey, MaxValuesWithPayload, MinValues, NanPolicy};
+#[cfg(feature = "alloc")]
+use crate::{GroupedMaxValues, MaxValuesVec};
 
 /// Iterator extension trait, 
-/// which adds [`MaxValuesIterExt::max_values`] method
+/// which adds [`MaxValuesIterExt::max_values`], [`MaxValuesIterExt::min_values`]
+/// and their variants with custom comparison
 pub trait MaxValuesIterExt: Iterator {
     /// Returns iterator, which iterates over n biggest elements
     /// of given iterator.
     fn max_values<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Ord {
         MaxValues::from_iter(self).into_iter()
     }
+
+    /// Returns iterator, which iterates over n biggest elements
+    /// of given iterator in descending order (the biggest element goes first).
+    fn max_values_sorted<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Ord {
+        MaxValues::from_iter(self).into_sorted_iter()
+    }
+
+    /// Returns iterator, which iterates over `capacity` biggest elements
+    /// of given iterator. Unlike [`MaxValuesIterExt::max_values`], the number of elements is chosen at runtime.
+    #[cfg(feature = "alloc")]
+    fn max_values_dyn(self, capacity: usize) -> alloc::vec::IntoIter<Self::Item> where Self: Sized, Self::Item: Ord {
+        MaxValuesVec::from_iter_with_capacity(capacity, self).into_iter()
+    }
+
+    /// Returns iterator, which iterates over n elements of given iterator with the biggest scores,
+    /// together with their scores, in descending order of the scores.
+    /// Unlike [`MaxValuesIterExt::max_values_by_key`], the score is computed only once for every element.
+    fn max_values_by_score<const N: usize, K: Ord>(self, mut score: impl FnMut(&Self::Item) -> K) -> arrayvec::IntoIter<(K, Self::Item), N> where Self: Sized {
+        let mut values = MaxValuesWithPayload::<_, _, N>::new();
+        self.for_each(|x| values.push(score(&x), x));
+        values.into_sorted_iter()
+    }
+
+    /// Returns iterator, which iterates over n biggest distinct elements
+    /// of given iterator.
+    fn max_distinct_values<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Ord {
+        MaxDistinctValues::from_iter(self).into_iter()
+    }
+
+    /// Returns iterator, which iterates over n biggest elements
+    /// of given iterator with distinct keys. Of the elements with the same key only the biggest one is kept.
+    fn max_distinct_values_by_key<const N: usize, K: Eq>(self, key: impl Fn(&Self::Item) -> K) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Ord {
+        let mut values = MaxDistinctValuesByKey::<_, _, _, N>::new(key);
+        self.for_each(|x| values.push(x));
+        values.into_iter()
+    }
+
+    /// Returns iterator, which iterates over n biggest floating point numbers
+    /// of given iterator. NaN values are ignored.
+    fn max_floats<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Float {
+        MaxFloats::from_iter(self).into_iter()
+    }
+
+    /// Returns iterator, which iterates over n biggest floating point numbers
+    /// of given iterator. NaN values are treated according to `policy`.
+    fn max_floats_with_policy<const N: usize>(self, policy: NanPolicy) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Float {
+        let mut values = MaxFloats::with_policy(policy);
+        self.for_each(|x| values.push(x));
+        values.into_iter()
+    }
+
+    /// Returns iterator, which iterates over n smallest elements
+    /// of given iterator.
+    fn min_values<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized, Self::Item: Ord {
+        MinValues::from_iter(self).into_iter()
+    }
+
+    /// Splits the elements of given iterator into groups by `key`, and collects n biggest elements of every group.
+    #[cfg(feature = "alloc")]
+    fn max_values_per_group<const N: usize, K: Ord>(self, mut key: impl FnMut(&Self::Item) -> K) -> GroupedMaxValues<K, Self::Item, N> where Self: Sized, Self::Item: Ord {
+        let mut values = GroupedMaxValues::new();
+        self.for_each(|x| values.push(key(&x), x));
+        values
+    }
+
+    /// Returns iterator, which iterates over n biggest elements
+    /// of given iterator, compared by `compare` function.
+    fn max_values_by<const N: usize>(self, compare: impl Fn(&Self::Item, &Self::Item) -> Ordering) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized {
+        let mut values = MaxValuesBy::<_, _, N>::new(compare);
+        self.for_each(|x| values.push(x));
+        values.into_iter()
+    }
+
+    /// Returns iterator, which iterates over n biggest elements
+    /// of given iterator, compared by `compare` function, in descending order.
+    fn max_values_sorted_by<const N: usize>(self, compare: impl Fn(&Self::Item, &Self::Item) -> Ordering) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized {
+        let mut values = MaxValuesBy::<_, _, N>::new(compare);
+        self.for_each(|x| values.push(x));
+        values.into_sorted_iter()
+    }
+
+    /// Returns iterator, which iterates over n elements
+    /// of given iterator with the biggest keys.
+    fn max_values_by_key<const N: usize, K: Ord>(self, key: impl Fn(&Self::Item) -> K) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized {
+        let mut values = MaxValuesByKey::<_, _, _, N>::new(key);
+        self.for_each(|x| values.push(x));
+        values.into_iter()
+    }
+
+    /// Returns iterator, which iterates over n elements
+    /// of given iterator with the biggest keys in descending order of the keys.
+    fn max_values_sorted_by_key<const N: usize, K: Ord>(self, key: impl Fn(&Self::Item) -> K) -> arrayvec::IntoIter<Self::Item, N> where Self: Sized {
+        let mut values = MaxValuesByKey::<_, _, _, N>::new(key);
+        self.for_each(|x| values.push(x));
+        values.into_sorted_iter()
+    }
 }
 
 impl <I: Iterator> MaxValuesIterExt for I {}
@@ -5,6 +5,7 @@
 //! The basic usage of this package looks like this
 //! ```rust
 //! use max_values::MaxValues;
+//! use std::collections::HashSet;
 //!
 //! fn main() {
 //!     let mut values = MaxValues::<i32, 3>::new();
@@ -27,33 +28,57 @@
 //! ```
 //! Beware, that ```MaxValues``` struct doesn't guarantee any order of elements. That's why we're transforming it into hashset for ```assert_eq``` macro.
 //!
+//! ## Sorted values
+//! If the order is needed, use ```into_sorted_array``` or ```into_sorted_iter``` (the biggest value goes first),
+//! as well as ```into_ascending_array``` or ```into_ascending_iter``` (the smallest value goes first).
+//! The values are sorted in place, so no additional allocation is made:
+//! ```rust
+//! use max_values::MaxValues;
+//!
+//! let values = MaxValues::<i32, 3>::from_iter([0, 1, 5, 7, 2, 3]);
+//! assert_eq!(values.clone().into_sorted_array().as_slice(), [7, 5, 3]);
+//! assert_eq!(values.into_ascending_iter().collect::<Vec<_>>(), [3, 5, 7]);
+//! ```
+//!
+//! Iterator extension trait has sorted versions of its methods too:
+//! ```rust
+//! use max_values::MaxValuesIterExt;
+//!
+//! let scores = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+//! assert_eq!(scores.into_iter().max_values_sorted::<3>().collect::<Vec<_>>(), [15, 10, 7]);
+//! ```
+//!
 //! ## Using iterator adaptor
 //! Common pattern is to iterate through collection and push it elements to `MaxValues` like this:
 //! ```rust
 //! use max_values::MaxValues;
+//! use std::collections::HashSet;
 //!
 //! fn main() {
 //!     let arr = [0, 1, 5, 7, 2, 3];
 //! 
-//!     let values = MaxValues::<i32, 3>::new();
+//!     let mut values = MaxValues::<i32, 3>::new();
 //!     for i in arr {
 //!         values.push(i);
 //!     }
 //! 
-//!     assert_eq!(values.into_iter().collect::<HashSet<_>>, HashSet::from([3, 5, 7]));
+//!     assert_eq!(values.into_iter().collect::<HashSet<_>>(), HashSet::from([3, 5, 7]));
 //! }
 //! ```
 //!
 //! That's why ```MaxValues``` implements ```FromIterable<T>```:
 //! ```rust
+//! # use max_values::MaxValues;
+//! # use std::collections::HashSet;
 //! let arr = [0, 1, 5, 7, 2, 3];
-//! let values = MaxValues::<i32, 3>::from_iter(arr.into_iter());
+//! let values = MaxValues::<i32, 3>::from_iter(arr);
 //! assert_eq!(values.into_iter().collect::<HashSet<_>>(), HashSet::from([3, 5, 7]));
 //! ```
 //! 
 //! Also, you can use iterator extension trait ```MaxValuesIterExt``` to iterate over max values of iterator:
 //! ```rust
 //! use max_values::MaxValuesIterExt;
+//! use std::collections::HashSet;
 //! 
 //! fn main() {
 //!     let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
@@ -63,7 +88,202 @@
 //!     );
 //! }
 //! ```
+//!
+//! ## Getting min values
+//! ```MinValues``` is the counterpart of ```MaxValues```, which keeps n smallest values instead:
+//! ```rust
+//! use max_values::{MinValues, MaxValuesIterExt};
+//! use std::collections::HashSet;
+//!
+//! let values = MinValues::<i32, 3>::from_iter([1, 5, 2, 4, 7, 10, 0, 15, 3]);
+//! assert_eq!(values.into_iter().collect::<HashSet<_>>(), HashSet::from([0, 1, 2]));
+//!
+//! let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+//! assert_eq!(
+//!     values.into_iter().min_values::<3>().collect::<HashSet<_>>(),
+//!     HashSet::from([0, 1, 2])
+//! );
+//! ```
+//!
+//! ## Slices
+//! For slices, which are already in memory, there is ```top_n_in_place``` function, which moves n biggest values to the beginning of the slice,
+//! and ```MaxValues::from_slice```. Both of them use quickselect instead of the heap, if n is big enough comparing to the length of the slice:
+//! ```rust
+//! use max_values::{top_n_in_place, MaxValues};
+//!
+//! let mut scores = [4, 8, 1, 9, 0, 3, 7];
+//! let top = top_n_in_place(&mut scores, 3);
+//! top.sort();
+//! assert_eq!(top, [7, 8, 9]);
+//!
+//! let values = MaxValues::<i32, 2>::from_slice(&[4, 8, 1, 9, 0, 3, 7]);
+//! assert_eq!(values.into_sorted_array().as_slice(), [9, 8]);
+//! ```
+//!
+//! ## Merging
+//! Max values of different parts of the data, e.g. computed in different threads, can be combined
+//! with ```merge``` method or ```+``` operator:
+//! ```rust
+//! use max_values::MaxValues;
+//!
+//! let mut first = MaxValues::<i32, 3>::from_iter([3, 14, 15]);
+//! let second = MaxValues::<i32, 3>::from_iter([9, 2, 6]);
+//! first.merge(second);
+//! first += MaxValues::from_iter([5, 35, 8]);
+//! assert_eq!(first.into_sorted_array().as_slice(), [35, 15, 14]);
+//! ```
+//!
+//! ## Removing and updating values
+//! The values can't be mutated directly, since it can invalidate the binary heap, but they can be removed
+//! with ```retain``` and ```remove_where``` methods, and the smallest value can be changed through ```peek_mut``` handle:
+//! ```rust
+//! use max_values::{MaxValues, PeekMut};
+//!
+//! let mut values = MaxValues::<i32, 4>::from_iter([3, 14, 15, 9, 2, 6]);
+//! values.retain(|&x| x != 14);
+//! assert_eq!(values.remove_where(|&x| x > 10).as_slice(), [15]);
+//! *values.peek_mut().unwrap() += 10;
+//! assert_eq!(values.clone().into_sorted_array().as_slice(), [16, 9]);
+//! assert_eq!(PeekMut::pop(values.peek_mut().unwrap()), 9);
+//! ```
+//!
+//! ## Parallel iterators
+//! With ```rayon``` feature enabled, ```MaxValues``` implements ```FromParallelIterator```,
+//! and ```MaxValuesParIterExt``` adds ```max_values``` method to parallel iterators.
+//! Every thread collects its own max values, which are merged afterwards.
+//!
+//! ## Serialization
+//! With ```serde``` feature enabled, ```MaxValues``` implements ```Serialize``` and ```Deserialize```.
+//! It is serialized as a sequence of its values. Deserialization fails if the sequence is longer than N,
+//! and the values may come in any order.
+//!
+//! ## Runtime capacity
+//! If the number of values isn't known at compile time, use ```MaxValuesVec```, which stores the values in ```Vec```.
+//! It requires ```alloc``` feature, which is enabled by ```std``` feature:
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use max_values::{MaxValuesVec, MaxValuesIterExt};
+//!
+//! let k = 3;
+//! let values = MaxValuesVec::from_iter_with_capacity(k, [0, 1, 5, 7, 2, 3]);
+//! assert_eq!(values.into_sorted_vec(), [7, 5, 3]);
+//!
+//! let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+//! assert_eq!(values.into_iter().max_values_dyn(k).count(), 3);
+//! # }
+//! ```
+//!
+//! ## Equal values
+//! If a value is equal to the smallest value, ```MaxValues``` replaces the smallest value with it, and it's not specified, which of older equal values are kept.
+//! ```StableMaxValues``` allows to choose which of them are kept with ```TiePolicy```,
+//! and returns sorted equal values in the order they were pushed, so that the result is reproducible.
+//!
+//! ## Distinct values
+//! ```MaxDistinctValues``` rejects the values, which are equal to one of its values, and
+//! ```MaxDistinctValuesByKey``` keeps only the biggest of the values with the same key:
+//! ```rust
+//! use max_values::{MaxDistinctValues, MaxValuesIterExt};
+//!
+//! let values = MaxDistinctValues::<i32, 3>::from_iter([2, 4, 5, 4, 5, 1]);
+//! assert_eq!(values.into_sorted_array().as_slice(), [5, 4, 2]);
+//!
+//! let scores = [(5, "a"), (3, "b"), (8, "a"), (4, "c")];
+//! let mut top = scores.into_iter().max_distinct_values_by_key::<2, _>(|x| x.1).collect::<Vec<_>>();
+//! top.sort();
+//! assert_eq!(top, [(4, "c"), (8, "a")]);
+//! ```
+//!
+//! ## Payload
+//! When the values are ranked by a score, and the rest of the value doesn't implement ```Ord``` or is expensive to compare,
+//! use ```MaxValuesWithPayload```, which stores ```(key, payload)``` pairs and compares only keys.
+//! Iterator adaptor ```max_values_by_score``` computes the score only once for every element and yields the pairs in ranked order:
+//! ```rust
+//! use max_values::MaxValuesIterExt;
+//!
+//! let documents = ["lorem", "ipsum", "consectetur", "sit", "adipiscing"];
+//! let top = documents.into_iter().max_values_by_score::<2, _>(|document| document.len()).collect::<Vec<_>>();
+//! assert_eq!(top, [(11, "consectetur"), (10, "adipiscing")]);
+//! ```
+//!
+//! ## Floating point numbers
+//! ```f32``` and ```f64``` don't implement ```Ord```, so there is ```MaxFloats``` for them.
+//! The numbers are compared with ```total_cmp```, and NaN values are treated according to ```NanPolicy```:
+//! ignored (by default), or treated as the largest or the smallest values.
+//! ```rust
+//! use max_values::{MaxFloats, MaxValuesIterExt, NanPolicy};
+//!
+//! let values = MaxFloats::<f64, 2>::from_iter([0.5, f64::NAN, 2.5, -1.0]);
+//! assert_eq!(values.into_sorted_array().as_slice(), [2.5, 0.5]);
+//!
+//! let mut values = MaxFloats::<f64, 2>::with_policy(NanPolicy::Largest);
+//! values.extend([0.5, f64::NAN, 2.5, -1.0]);
+//! assert!(values.into_sorted_array()[0].is_nan());
+//!
+//! let floats = [0.5, f64::NAN, 2.5, -1.0];
+//! assert_eq!(floats.into_iter().max_floats::<1>().next(), Some(2.5));
+//! ```
+//!
+//! ## Groups
+//! ```GroupedMaxValues``` keeps max values for every group of the values, identified by the key.
+//! It requires ```alloc``` feature, which is enabled by ```std``` feature:
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use max_values::MaxValuesIterExt;
+//!
+//! let products = [("fruit", 30), ("fruit", 20), ("tools", 15), ("fruit", 45), ("tools", 25), ("books", 5)];
+//! let groups = products.into_iter().max_values_per_group::<2, _>(|product| product.0);
+//! assert_eq!(groups.get("fruit").unwrap().clone().into_sorted_array().as_slice(), [("fruit", 45), ("fruit", 30)]);
+//! assert_eq!(groups.len(), 3);
+//! # }
+//! ```
+//!
+//! ## Sliding window
+//! ```WindowedMaxValues``` keeps max values of the last pushed values only, either of given number of them or of given time span.
+//! It requires ```alloc``` feature, which is enabled by ```std``` feature:
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use max_values::{Window, WindowedMaxValues};
+//!
+//! let mut latencies = WindowedMaxValues::<u32, 2>::new(Window::Count(3));
+//! latencies.extend([120, 80, 95, 40]);
+//! assert_eq!(latencies.into_sorted_array().as_slice(), [95, 80]);
+//!
+//! let mut latencies = WindowedMaxValues::<u32, 2>::new(Window::Span(60));
+//! latencies.push_at(0, 120);
+//! latencies.push_at(30, 80);
+//! latencies.push_at(60, 40);
+//! assert_eq!(latencies.into_sorted_array().as_slice(), [80, 40]);
+//! # }
+//! ```
+//!
+//! ## no_std
+//! The crate is ```no_std``` compatible. Disable default ```std``` feature to use it without standard library,
+//! and enable ```alloc``` feature to use the data structures, which allocate memory.
+//!
+//! ## Custom comparison
+//! For types, which don't implement ```Ord```, or when elements have to be compared by one of their fields,
+//! use ```MaxValuesBy``` and ```MaxValuesByKey```:
+//! ```rust
+//! use max_values::{MaxValuesByKey, MaxValuesIterExt};
+//! use std::collections::HashSet;
+//!
+//! let mut values = MaxValuesByKey::<(&str, u32), _, _, 3>::new(|row: &(&str, u32)| row.1);
+//! for row in [("a", 3), ("b", 1), ("c", 7), ("d", 5), ("e", 2)] {
+//!     values.push(row);
+//! }
+//! assert_eq!(values.iter().map(|row| row.0).collect::<HashSet<_>>(), HashSet::from(["a", "c", "d"]));
+//!
+//! let floats = [0.5, 2.5, -1.0, 1.5];
+//! assert_eq!(
+//!     floats.into_iter().max_values_by::<2>(f64::total_cmp).map(|x| x.to_string()).collect::<HashSet<_>>(),
+//!     HashSet::from(["1.5".to_string(), "2.5".to_string()])
+//! );
+//! ```
  
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 // This file declares the struct, as well as defines its high-level functions and imports other modules
 use core::slice::Iter;
@@ -83,6 +303,20 @@ impl<T: Ord, const N: usize> MaxValues<T, N> {
         MaxValues { data: ArrayVec::new() }
     }
 
+    /// Creates [`MaxValues`] data structure, which contains all the values of given array.
+    /// The binary heap is built in O(N), which is faster than pushing the values one by one.
+    pub fn from_array(values: [T; N]) -> Self {
+        Self::from_arrayvec(ArrayVec::from(values))
+    }
+
+    /// Creates [`MaxValues`] data structure, which contains all the values of given [`ArrayVec`].
+    /// The binary heap is built in O(N), which is faster than pushing the values one by one.
+    pub fn from_arrayvec(values: ArrayVec<T, N>) -> Self {
+        let mut data = values;
+        push::heapify_array(&mut data, T::cmp);
+        MaxValues { data }
+    }
+
     /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N values are pushed to TopValues. 
     pub fn to_values(self) -> ArrayVec<T, N> {
         self.data
@@ -90,14 +324,83 @@ impl<T: Ord, const N: usize> MaxValues<T, N> {
 
     /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N values are pushed to TopValues.
     /// The reason why there is no mutable version of this method is that mutating the elements of array can invalidate the binary heap used by this data structure.
+    /// Use [`MaxValues::retain`], [`MaxValues::remove_where`] or [`MaxValues::peek_mut`] to change the values instead.
     pub fn as_values(&self) -> &ArrayVec<T, N> {
         &self.data
     }
 
     /// Returns an iterator over the values of self.
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.data.iter()
     }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if self contains N values, so that every next push replaces one of them or is rejected.
+    pub fn is_full(&self) -> bool {
+        self.data.is_full()
+    }
+
+    /// Returns the maximum number of values, which self can hold, which is N.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns reference to the smallest of the values, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns reference to the smallest of the values only if self is full.
+    /// Values, which are smaller than the threshold, will be rejected by [`MaxValues::push`].
+    pub fn threshold(&self) -> Option<&T> {
+        if self.is_full() {
+            self.peek_min()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if pushing given value would change self.
+    pub fn would_accept(&self, value: &T) -> bool {
+        match self.threshold() {
+            Some(min) => min <= value,
+            None => !self.is_full(),
+        }
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    /// The values are sorted in place, without any additional allocation.
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        push::sort_heap(&mut data, T::cmp);
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in ascending order (the smallest value goes first).
+    pub fn into_ascending_array(self) -> ArrayVec<T, N> {
+        let mut data = self.into_sorted_array();
+        data.reverse();
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in ascending order (the smallest value goes first).
+    pub fn into_ascending_iter(self) -> IntoIter<T, N> {
+        self.into_ascending_array().into_iter()
+    }
 }
 
 impl<T: Ord, const N: usize> Default for MaxValues<T, N> {
@@ -112,11 +415,13 @@ impl<T: Ord, const N: usize> AsRef<[T]> for MaxValues<T, N> {
     }
 }
 
+// First N values are turned into the heap at once, and only the rest are pushed one by one.
 impl<T: Ord, const N: usize> FromIterator<T> for MaxValues<T, N> {
     fn from_iter<IterT: IntoIterator<Item = T>>(iter: IterT) -> Self {
-        let mut values = MaxValues::new();
-        iter.into_iter().for_each(|x| values.push(x));
-        return values;
+        let mut iter = iter.into_iter();
+        let mut values = MaxValues::from_arrayvec(iter.by_ref().take(N).collect());
+        iter.for_each(|x| values.push(x));
+        values
     }
 }
 
@@ -131,11 +436,47 @@ impl<T: Ord, const N: usize> IntoIterator for MaxValues<T, N> {
 
 mod push;
 mod iter_ext;
+mod min_values;
+mod by;
+mod floats;
+mod payload;
+mod stable;
+mod distinct;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+mod grouped;
+#[cfg(feature = "alloc")]
+mod window;
+mod merge;
+mod remove;
+mod slice;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "serde")]
+mod serialize;
 pub use iter_ext::MaxValuesIterExt;
+pub use push::Pushed;
+pub use remove::PeekMut;
+pub use slice::top_n_in_place;
+pub use min_values::MinValues;
+pub use by::{MaxValuesBy, MaxValuesByKey};
+pub use floats::{Float, MaxFloats, NanPolicy};
+pub use payload::MaxValuesWithPayload;
+pub use stable::{StableMaxValues, TiePolicy};
+pub use distinct::{MaxDistinctValues, MaxDistinctValuesByKey};
+#[cfg(feature = "alloc")]
+pub use vec::MaxValuesVec;
+#[cfg(feature = "alloc")]
+pub use grouped::GroupedMaxValues;
+#[cfg(feature = "alloc")]
+pub use window::{Window, WindowedMaxValues};
+#[cfg(feature = "rayon")]
+pub use par_iter::MaxValuesParIterExt;
 
 #[cfg(test)]
 mod tests {
-    use crate::{MaxValues, MaxValuesIterExt};
+    use crate::{MaxValues, MaxValuesIterExt, Pushed};
     use std::collections::HashSet;
 
     #[test]
@@ -168,6 +509,107 @@ mod tests {
     }
 
     #[test]
+    fn test_sorted_iterator() {
+        let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+        assert_eq!(values.into_iter().max_values_sorted::<3>().collect::<Vec<_>>(), [15, 10, 7]);
+    }
+
+    #[test]
+    fn test_sorted() {
+        let arr = [4, 8, 1, 9, 9, 0, 3, 7, 5, 2, 6];
+        let values = MaxValues::<i32, 6>::from_iter(arr);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [9, 9, 8, 7, 6, 5]);
+        assert_eq!(values.clone().into_sorted_iter().collect::<Vec<_>>(), [9, 9, 8, 7, 6, 5]);
+        assert_eq!(values.into_ascending_iter().collect::<Vec<_>>(), [5, 6, 7, 8, 9, 9]);
+
+        let values = MaxValues::<i32, 16>::from_iter(arr);
+        assert_eq!(values.into_ascending_array().as_slice(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 9]);
+    }
+
+    #[test]
+    fn test_threshold() {
+        let mut values = MaxValues::<i32, 3>::new();
+        assert!(values.is_empty());
+        assert_eq!(values.capacity(), 3);
+        assert_eq!(values.peek_min(), None);
+        assert!(values.would_accept(&-100));
+
+        values.push(5);
+        values.push(2);
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.peek_min(), Some(&2));
+        assert_eq!(values.threshold(), None);
+        assert!(!values.is_full());
+
+        values.push(7);
+        assert!(values.is_full());
+        assert_eq!(values.threshold(), Some(&2));
+        assert!(values.would_accept(&2));
+        assert!(values.would_accept(&3));
+        assert!(!values.would_accept(&1));
+
+        values.push(3);
+        assert_eq!(values.threshold(), Some(&3));
+    }
+
+    #[test]
+    fn test_push_report() {
+        let mut values = MaxValues::<String, 2>::new();
+        assert_eq!(values.push_report("b".to_string()), Pushed::Inserted);
+        assert_eq!(values.push_report("d".to_string()), Pushed::Inserted);
+        assert_eq!(values.push_report("a".to_string()), Pushed::Rejected("a".to_string()));
+        assert_eq!(values.push_report("c".to_string()), Pushed::Replaced("b".to_string()));
+        assert_eq!(values.push_report("e".to_string()), Pushed::Replaced("c".to_string()));
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut values = MaxValues::<i32, 0>::new();
+        assert_eq!(values.push_report(1), Pushed::Rejected(1));
+        values.push(2);
+        assert!(values.is_empty());
+        assert!(values.is_full());
+        assert_eq!(values.threshold(), None);
+        assert!(!values.would_accept(&3));
+
+        values.merge(MaxValues::from_iter([4, 5]));
+        values += MaxValues::new();
+        assert!(values.into_sorted_array().is_empty());
+
+        assert_eq!([1, 2, 3].into_iter().max_values::<0>().next(), None);
+        assert_eq!([1, 2, 3].into_iter().max_values_sorted::<0>().next(), None);
+        assert_eq!([1, 2, 3].into_iter().min_values::<0>().next(), None);
+    }
+
+    #[test]
+    fn test_from_array() {
+        let mut values = MaxValues::from_array([5, 1, 9, 3, 7, 2]);
+        assert_eq!(values.peek_min(), Some(&1));
+        values.push(4);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [9, 7, 5, 4, 3, 2]);
+
+        let mut values = MaxValues::<i32, 6>::from_arrayvec([8, 4, 6].into_iter().collect());
+        assert_eq!(values.peek_min(), Some(&4));
+        values.push(5);
+        assert_eq!(values.into_sorted_array().as_slice(), [8, 6, 5, 4]);
+    }
+
+    #[test]
+    fn test_push_slice() {
+        let input: Vec<u32> = (0..1000u32).map(|x| x.wrapping_mul(2654435761) % 500).collect();
+        let mut values = MaxValues::<u32, 10>::new();
+        values.push_slice(&input[..3]);
+        assert_eq!(values.len(), 3);
+        values.push_slice(&input[3..]);
+        assert_eq!(values.into_sorted_array(), MaxValues::<u32, 10>::from_iter(input.iter().copied()).into_sorted_array());
+
+        let mut values = MaxValues::<u32, 0>::new();
+        values.push_slice(&input);
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_example1() {
         let arr = [0, 1, 5, 7, 2, 3];
         let values = MaxValues::<i32, 3>::from_iter(arr.into_iter());
@@ -0,0 +1,70 @@
+// Combining of several MaxValues into one
+use core::mem;
+use core::ops::{Add, AddAssign};
+use crate::MaxValues;
+
+impl<T: Ord, const N: usize> MaxValues<T, N> {
+    /// Pushes all the values of `other` into self, so that self contains max values of both.
+    /// The smaller of two data structures is pushed into the bigger one.
+    pub fn merge(&mut self, mut other: MaxValues<T, N>) {
+        if self.data.len() < other.data.len() {
+            mem::swap(self, &mut other);
+        }
+        other.data.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl<T: Ord, const N: usize> Extend<T> for MaxValues<T, N> {
+    fn extend<IterT: IntoIterator<Item = T>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl<T: Ord, const N: usize> Add for MaxValues<T, N> {
+    type Output = MaxValues<T, N>;
+
+    fn add(mut self, other: MaxValues<T, N>) -> MaxValues<T, N> {
+        self.merge(other);
+        self
+    }
+}
+
+impl<T: Ord, const N: usize> AddAssign for MaxValues<T, N> {
+    fn add_assign(&mut self, other: MaxValues<T, N>) {
+        self.merge(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaxValues;
+
+    #[test]
+    fn test_merge() {
+        let mut first = MaxValues::<i32, 4>::from_iter([3, 9, 1, 4, 12]);
+        let second = MaxValues::<i32, 4>::from_iter([8, 2]);
+        first.merge(second);
+        assert_eq!(first.into_sorted_array().as_slice(), [12, 9, 8, 4]);
+
+        let mut first = MaxValues::<i32, 4>::from_iter([5]);
+        let second = MaxValues::<i32, 4>::from_iter([3, 9, 1, 4, 12]);
+        first.merge(second);
+        assert_eq!(first.into_sorted_array().as_slice(), [12, 9, 5, 4]);
+    }
+
+    #[test]
+    fn test_extend_and_add() {
+        let mut values = MaxValues::<i32, 3>::from_iter([7, 1]);
+        values.extend([4, 0, 6]);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [7, 6, 4]);
+
+        values += MaxValues::from_iter([5, 10]);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [10, 7, 6]);
+
+        let shards = [vec![3, 14, 15], vec![9, 2, 6], vec![5, 35, 8]];
+        let total = shards.iter()
+            .map(|shard| MaxValues::<i32, 3>::from_iter(shard.iter().copied()))
+            .fold(MaxValues::new(), |acc, x| acc + x);
+        assert_eq!(total.into_sorted_array().as_slice(), [35, 15, 14]);
+    }
+}
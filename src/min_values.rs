@@ -0,0 +1,102 @@
+use core::slice::Iter;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::push;
+
+
+/// Counterpart of [`MaxValues`](crate::MaxValues) for getting min values out of given
+#[derive(Debug, Clone)]
+pub struct MinValues<T: Ord, const N: usize> {
+    data: ArrayVec<T, N>
+}
+
+impl<T: Ord, const N: usize> MinValues<T, N> {
+    /// Creates new empty [`MinValues`] data structure
+    pub fn new() -> Self {
+        MinValues { data: ArrayVec::new() }
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is smaller than one of the elements.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        push(&mut self.data, value, |a: &T, b: &T| b.cmp(a));
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values. Note, that returned array may contain less than N values, if less than N values are pushed to MinValues.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of values. Note, that returned array may contain less than N values, if less than N values are pushed to MinValues.
+    /// The reason why there is no mutable version of this method is that mutating the elements of array can invalidate the binary heap used by this data structure.
+    pub fn as_values(&self) -> &ArrayVec<T, N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Ord, const N: usize> Default for MinValues<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> AsRef<[T]> for MinValues<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for MinValues<T, N> {
+    fn from_iter<IterT: IntoIterator<Item = T>>(iter: IterT) -> Self {
+        let mut values = MinValues::new();
+        iter.into_iter().for_each(|x| values.push(x));
+        values
+    }
+}
+
+impl<T: Ord, const N: usize> IntoIterator for MinValues<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MinValues, MaxValuesIterExt};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_min_values() {
+        let mut values = MinValues::<i32, 3>::new();
+        values.push(4);
+        assert_eq!(values.as_ref(), [4]);
+
+        values.push(3);
+        values.push(2);
+        assert_eq!(values.iter().copied().collect::<HashSet<_>>(), HashSet::from([2, 3, 4]));
+
+        values.push(5);
+        assert_eq!(values.iter().copied().collect::<HashSet<_>>(), HashSet::from([2, 3, 4]));
+
+        values.push(1);
+        assert_eq!(values.iter().copied().collect::<HashSet<_>>(), HashSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_iterator() {
+        let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+        assert_eq!(
+            values.into_iter().min_values::<3>().collect::<HashSet<_>>(),
+            HashSet::from([0, 1, 2])
+        );
+    }
+}
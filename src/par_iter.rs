@@ -0,0 +1,55 @@
+// Rayon parallel iterators support
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+use crate::MaxValues;
+
+/// Parallel iterator extension trait,
+/// which adds [`MaxValuesParIterExt::max_values`] method.
+/// Every thread collects max values of its part of the iterator, which are merged afterwards.
+pub trait MaxValuesParIterExt: ParallelIterator {
+    /// Returns iterator, which iterates over n biggest elements
+    /// of given parallel iterator.
+    fn max_values<const N: usize>(self) -> arrayvec::IntoIter<Self::Item, N> where Self::Item: Ord {
+        MaxValues::from_par_iter(self).into_iter()
+    }
+}
+
+impl<I: ParallelIterator> MaxValuesParIterExt for I {}
+
+impl<T: Ord + Send, const N: usize> FromParallelIterator<T> for MaxValues<T, N> {
+    fn from_par_iter<IterT: IntoParallelIterator<Item = T>>(par_iter: IterT) -> Self {
+        par_iter.into_par_iter()
+            .fold(MaxValues::new, |mut values, x| {
+                values.push(x);
+                values
+            })
+            .reduce(MaxValues::new, |first, second| first + second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+    use crate::{MaxValues, MaxValuesIterExt, MaxValuesParIterExt};
+
+    fn scores(len: u64) -> Vec<u64> {
+        (0..len).map(|x| x.wrapping_mul(2654435761) % 10_000).collect()
+    }
+
+    #[test]
+    fn test_par_iter() {
+        let data = scores(100_000);
+        let mut parallel = data.par_iter().copied().max_values::<16>().collect::<Vec<_>>();
+        let mut sequential = MaxValuesIterExt::max_values::<16>(data.iter().copied()).collect::<Vec<_>>();
+        parallel.sort();
+        sequential.sort();
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn test_collect() {
+        let data = scores(10_000);
+        let parallel: MaxValues<u64, 5> = data.par_iter().copied().collect();
+        let sequential: MaxValues<u64, 5> = data.iter().copied().collect();
+        assert_eq!(parallel.into_sorted_array(), sequential.into_sorted_array());
+    }
+}
@@ -0,0 +1,141 @@
+// Variant of MaxValues, which stores payload together with the key, but compares only keys
+use core::slice::Iter;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::{push, sort_heap};
+use crate::Pushed;
+
+
+/// Struct for getting max values out of given `(key, payload)` pairs. Only the keys are compared,
+/// so the payload doesn't have to implement [`Ord`].
+#[derive(Debug, Clone)]
+pub struct MaxValuesWithPayload<K: Ord, V, const N: usize> {
+    data: ArrayVec<(K, V), N>
+}
+
+impl<K: Ord, V, const N: usize> MaxValuesWithPayload<K, V, N> {
+    /// Creates new empty [`MaxValuesWithPayload`] data structure
+    pub fn new() -> Self {
+        MaxValuesWithPayload { data: ArrayVec::new() }
+    }
+
+    /// Pushes a pair into the data structure,
+    /// if its key is bigger than the key of one of the pairs.
+    /// May replace one of the previously pushed pairs.
+    pub fn push(&mut self, key: K, payload: V) {
+        self.push_report(key, payload);
+    }
+
+    /// Same as [`MaxValuesWithPayload::push`], but returns what happened to the data structure:
+    /// whether the pair is inserted, replaced the pair with the smallest key or is rejected.
+    pub fn push_report(&mut self, key: K, payload: V) -> Pushed<(K, V)> {
+        push(&mut self.data, (key, payload), |a: &(K, V), b: &(K, V)| a.0.cmp(&b.0))
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the pairs. Note, that returned array may contain less than N pairs, if less than N pairs are pushed to MaxValuesWithPayload.
+    pub fn to_values(self) -> ArrayVec<(K, V), N> {
+        self.data
+    }
+
+    /// Returns immutable reference to the [`ArrayVec`] of pairs. Note, that returned array may contain less than N pairs, if less than N pairs are pushed to MaxValuesWithPayload.
+    pub fn as_values(&self) -> &ArrayVec<(K, V), N> {
+        &self.data
+    }
+
+    /// Returns an iterator over the pairs of self.
+    pub fn iter(&self) -> Iter<'_, (K, V)> {
+        self.data.iter()
+    }
+
+    /// Returns the number of pairs in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no pairs are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns reference to the pair with the smallest key, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<&(K, V)> {
+        self.data.first()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the pairs, sorted in descending order of the keys (the biggest key goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<(K, V), N> {
+        let mut data = self.data;
+        sort_heap(&mut data, |a: &(K, V), b: &(K, V)| a.0.cmp(&b.0));
+        data
+    }
+
+    /// Consumes self and returns an iterator over the pairs in descending order of the keys (the biggest key goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<(K, V), N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<K: Ord, V, const N: usize> Default for MaxValuesWithPayload<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V, const N: usize> FromIterator<(K, V)> for MaxValuesWithPayload<K, V, N> {
+    fn from_iter<IterT: IntoIterator<Item = (K, V)>>(iter: IterT) -> Self {
+        let mut values = MaxValuesWithPayload::new();
+        iter.into_iter().for_each(|(key, payload)| values.push(key, payload));
+        values
+    }
+}
+
+impl<K: Ord, V, const N: usize> Extend<(K, V)> for MaxValuesWithPayload<K, V, N> {
+    fn extend<IterT: IntoIterator<Item = (K, V)>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|(key, payload)| self.push(key, payload));
+    }
+}
+
+impl<K: Ord, V, const N: usize> IntoIterator for MaxValuesWithPayload<K, V, N> {
+    type IntoIter = IntoIter<(K, V), N>;
+    type Item = (K, V);
+
+    fn into_iter(self) -> IntoIter<(K, V), N> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use crate::{MaxValuesIterExt, MaxValuesWithPayload};
+
+    // Payload, which doesn't implement Ord
+    #[derive(Debug, PartialEq)]
+    struct Document(&'static str);
+
+    #[test]
+    fn test_with_payload() {
+        let mut values = MaxValuesWithPayload::<u32, Document, 2>::new();
+        values.push(3, Document("a"));
+        values.push(9, Document("b"));
+        values.push(1, Document("c"));
+        values.push(5, Document("d"));
+        assert_eq!(values.into_sorted_array().as_slice(), [(9, Document("b")), (5, Document("d"))]);
+    }
+
+    #[test]
+    fn test_iterator() {
+        let calls = Cell::new(0);
+        let documents = ["lorem", "ipsum", "dolor", "sit", "amet", "consectetur"];
+        let top = documents.into_iter()
+            .max_values_by_score::<3, _>(|document| {
+                calls.set(calls.get() + 1);
+                document.len()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(top[0], (11, "consectetur"));
+        assert_eq!(top[1].0, 5);
+        assert_eq!(top[2].0, 5);
+        assert_eq!(calls.get(), documents.len());
+    }
+}
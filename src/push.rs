@@ -1,49 +1,415 @@
 // Separate module for the algorithm itself.
+// Every data structure of the crate keeps a binary heap, whose root is the "smallest" element
+// according to the given comparison, so the functions below are shared between all of them.
+use core::cmp::Ordering;
+use core::mem;
+use arrayvec::ArrayVec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use crate::MaxValues;
 
+/// Outcome of pushing an element, returned by [`MaxValues::push_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pushed<T> {
+    /// The element is added, and no other element is removed.
+    Inserted,
+    /// The element is added instead of the smallest element, which is returned.
+    Replaced(T),
+    /// The element is too small to be added, so it is returned back.
+    Rejected(T),
+}
+
 impl<T: Ord, const N: usize> MaxValues<T, N> {
-    /// Pushes an element into the data structure, 
+    /// Pushes an element into the data structure,
     /// if it is bigger than one of the elements.
     /// May replace one of the previously pushed elements.
+    /// If the element is equal to the smallest element, it replaces it instead of being rejected,
+    /// and it's not specified, which of older equal elements are kept (see [`StableMaxValues`](crate::StableMaxValues) to control it).
     pub fn push(&mut self, value: T) {
-        if self.data.len() < N {
-            self.push_back(value);
+        self.push_report(value);
+    }
+
+    /// Same as [`MaxValues::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the smallest element or is rejected.
+    /// If N is zero, every element is rejected.
+    pub fn push_report(&mut self, value: T) -> Pushed<T> {
+        push(&mut self.data, value, T::cmp)
+    }
+
+    /// Pushes all the elements of given slice into the data structure.
+    ///
+    /// Once the data structure is full, the slice is processed in chunks: every chunk is compared with the smallest value at once,
+    /// which can be vectorised by the compiler for primitive types, and only the chunks with values, that are big enough, are pushed one by one.
+    /// It is much faster than [`MaxValues::push`] if most of the values are rejected.
+    #[doc(alias = "extend_from_slice")]
+    pub fn push_slice(&mut self, values: &[T]) where T: Clone {
+        let split = (N - self.data.len()).min(values.len());
+        values[..split].iter().for_each(|x| self.push(x.clone()));
+
+        for chunk in values[split..].chunks(CHUNK_SIZE) {
+            let Some(min) = self.data.first() else {
+                return;
+            };
+            if any_accepted(chunk, min) {
+                chunk.iter().for_each(|x| if self.would_accept(x) {
+                    self.push(x.clone());
+                });
+            }
+        }
+    }
+}
+
+// Size of the chunks, which are compared with the smallest value at once by `push_slice`
+pub(crate) const CHUNK_SIZE: usize = 32;
+
+// Branchless check, so that the compiler can vectorise it
+pub(crate) fn any_accepted<T: Ord>(chunk: &[T], min: &T) -> bool {
+    any_accepted_by(chunk, |x| x >= min)
+}
+
+// Same as `any_accepted`, but with custom check of the values, which should be cheap and branchless as well
+pub(crate) fn any_accepted_by<T>(chunk: &[T], mut accepted: impl FnMut(&T) -> bool) -> bool {
+    chunk.iter().fold(false, |any, x| any | accepted(x))
+}
+
+// Arrays of at most this size are kept sorted in ascending order instead of being a general binary heap.
+// Sorted array is a valid binary heap as well, so every function, which reads the heap, works with both of them,
+// while insertion into a short sorted array is cheaper than sifting through the heap.
+const SMALL_N: usize = 8;
+
+// Pushes an element into the heap, ordered by `compare`.
+// The strategy is chosen at compile time: the heap with zero capacity rejects everything,
+// and small arrays are kept sorted.
+pub(crate) fn push<T, F, const N: usize>(data: &mut ArrayVec<T, N>, value: T, compare: F) -> Pushed<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if N == 0 {
+        Pushed::Rejected(value)
+    } else if N <= SMALL_N {
+        push_sorted(data, value, compare)
+    } else {
+        push_heap(data, value, compare)
+    }
+}
+
+fn push_heap<T, F, const N: usize>(data: &mut ArrayVec<T, N>, value: T, compare: F) -> Pushed<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() < N {
+        data.push(value);
+        push_back(data, compare);
+        Pushed::Inserted
+    } else {
+        push_forward(data, value, compare)
+    }
+}
+
+// Inserts an element into the array, sorted in ascending order, by moving it to its place with a linear scan.
+fn push_sorted<T, F, const N: usize>(data: &mut ArrayVec<T, N>, value: T, mut compare: F) -> Pushed<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() < N {
+        data.push(value);
+        let mut index = data.len() - 1;
+        while index > 0 && compare(&data[index - 1], &data[index]) == Ordering::Greater {
+            data.swap(index - 1, index);
+            index -= 1;
+        }
+        return Pushed::Inserted;
+    }
+
+    if compare(&data[0], &value) == Ordering::Greater {
+        return Pushed::Rejected(value);
+    }
+
+    let old = mem::replace(&mut data[0], value);
+    let mut index = 0;
+    while index + 1 < N && compare(&data[index], &data[index + 1]) == Ordering::Greater {
+        data.swap(index, index + 1);
+        index += 1;
+    }
+    Pushed::Replaced(old)
+}
+
+// Turns arbitrary array into the heap in place, using the same strategy as `push`.
+pub(crate) fn heapify_array<T, F, const N: usize>(data: &mut ArrayVec<T, N>, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if N <= SMALL_N {
+        data.sort_unstable_by(compare);
+    } else {
+        heapify(data, compare);
+    }
+}
+
+// Restores the heap, using the same strategy as `push`, after the element with given (0-based) index is changed.
+pub(crate) fn update<T, F, const N: usize>(data: &mut ArrayVec<T, N>, mut index: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if N <= SMALL_N {
+        if index > 0 && compare(&data[index - 1], &data[index]) == Ordering::Greater {
+            while index > 0 && compare(&data[index - 1], &data[index]) == Ordering::Greater {
+                data.swap(index - 1, index);
+                index -= 1;
+            }
+        } else {
+            while index + 1 < data.len() && compare(&data[index], &data[index + 1]) == Ordering::Greater {
+                data.swap(index, index + 1);
+                index += 1;
+            }
+        }
+    } else if index > 0 && compare(&data[(index - 1) / 2], &data[index]) == Ordering::Greater {
+        sift_up(data, index + 1, compare);
+    } else {
+        sift_down(data, index + 1, compare);
+    }
+}
+
+// Pushes an element into the heap, which is stored in `Vec` and holds at most `capacity` elements.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_vec<T, F>(data: &mut Vec<T>, capacity: usize, value: T, compare: F) -> Pushed<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() < capacity {
+        data.push(value);
+        push_back(data, compare);
+        Pushed::Inserted
+    } else if capacity == 0 {
+        Pushed::Rejected(value)
+    } else {
+        push_forward(data, value, compare)
+    }
+}
+
+// Push back to binary heap. The value is expected to be already appended to the end of data.
+fn push_back<T, F>(data: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = data.len();
+    sift_up(data, last, compare);
+}
+
+// Push forward to binary heap. Also may leave the heap as it is if the value is too small.
+fn push_forward<T, F>(data: &mut [T], value: T, mut compare: F) -> Pushed<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if compare(&data[0], &value) == Ordering::Greater {
+        return Pushed::Rejected(value);
+    }
+
+    let old = mem::replace(&mut data[0], value);
+    sift_down(data, 1, compare);
+    Pushed::Replaced(old)
+}
+
+// Same as push_forward, but swaps the value with the root instead of moving it, so that it works in place.
+// Equal values aren't swapped, since it doesn't change the heap.
+pub(crate) fn swap_forward<T, F>(data: &mut [T], value: &mut T, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if compare(&data[0], value) != Ordering::Less {
+        return;
+    }
+
+    mem::swap(&mut data[0], value);
+    sift_down(data, 1, compare);
+}
+
+// Moves the element with given (1-based) index up to its place in the heap.
+fn sift_up<T, F>(data: &mut [T], mut index: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while index > 1 && compare(&data[index / 2 - 1], &data[index - 1]) == Ordering::Greater {
+        data.swap(index / 2 - 1, index - 1);
+        index /= 2;
+    }
+}
+
+// Moves the element with given (1-based) index down to its place in the heap.
+// Every node has either two children, or only the left one (if it is the last node), or none.
+fn sift_down<T, F>(data: &mut [T], mut index: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = data.len();
+    loop {
+        let left = index * 2;
+        let right = left + 1;
+        let child = if right <= len {
+            if compare(&data[left - 1], &data[right - 1]) == Ordering::Greater { right } else { left }
+        } else if left <= len {
+            left
         } else {
-            self.push_forward(value);
+            break;
+        };
+
+        if compare(&data[index - 1], &data[child - 1]) != Ordering::Greater {
+            break;
         }
+        data.swap(index - 1, child - 1);
+        index = child;
     }
+}
 
-    // Push back to binary heap
-    fn push_back(&mut self, value: T) {
-        self.data.push(value);
-        let mut index = self.data.len();
-        while index > 1 && self.data[index / 2 - 1] > self.data[index - 1] {
-            self.data.swap(index / 2 - 1, index - 1);
-            index /= 2;
+// Turns arbitrary data into the heap in place, ordered by `compare`, using Floyd's algorithm in O(n).
+pub(crate) fn heapify<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for index in (1..=data.len() / 2).rev() {
+        sift_down(data, index, &mut compare);
+    }
+}
+
+// Sorts the heap in place in descending order according to `compare`,
+// by moving the root to the end of the shrinking heap.
+pub(crate) fn sort_heap<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for end in (1..data.len()).rev() {
+        data.swap(0, end);
+        sift_down(&mut data[..end], 1, &mut compare);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::collection::vec;
+    use arrayvec::ArrayVec;
+    use crate::{MaxValues, MinValues, Pushed};
+    use super::{push, push_heap, push_sorted, update, SMALL_N};
+
+    fn is_heap(data: &[u8]) -> bool {
+        (1..data.len()).all(|i| data[(i - 1) / 2] <= data[i])
+    }
+
+    // Compares MaxValues and MinValues with sorting the whole input and truncating it.
+    fn check_against_oracle<const N: usize>(input: &[u8]) {
+        let mut values = MaxValues::<u8, N>::new();
+        for &x in input {
+            let before = values.peek_min().copied();
+            match values.push_report(x) {
+                Pushed::Inserted => assert!(values.len() <= N),
+                Pushed::Replaced(old) => assert_eq!(Some(old), before),
+                Pushed::Rejected(rejected) => assert!(before.map_or(N == 0, |min| rejected < min)),
+            }
+            assert!(is_heap(values.as_values()));
+        }
+        let mut expected = input.to_vec();
+        expected.sort_by(|a, b| b.cmp(a));
+        expected.truncate(N);
+        assert_eq!(values.into_sorted_array().as_slice(), expected.as_slice());
+
+        let values = MaxValues::<u8, N>::from_iter(input.iter().copied());
+        assert!(is_heap(values.as_values()));
+        assert_eq!(values.into_sorted_array().as_slice(), expected.as_slice());
+
+        let mut values = MaxValues::<u8, N>::new();
+        values.push_slice(input);
+        assert!(is_heap(values.as_values()));
+        assert_eq!(values.into_sorted_array().as_slice(), expected.as_slice());
+
+        let mut min_values = MinValues::<u8, N>::from_iter(input.iter().copied()).to_values();
+        min_values.sort();
+        let mut expected = input.to_vec();
+        expected.sort();
+        expected.truncate(N);
+        assert_eq!(min_values.as_slice(), expected.as_slice());
+    }
+
+    // Pushes the same values into the sorted array and the heap, and checks that they behave the same way.
+    fn check_small_against_heap<const N: usize>(input: &[u8]) {
+        let mut sorted = ArrayVec::<u8, N>::new();
+        let mut heap = ArrayVec::<u8, N>::new();
+        for &x in input {
+            assert_eq!(push_sorted(&mut sorted, x, u8::cmp), push_heap(&mut heap, x, u8::cmp));
+            assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+            assert!(is_heap(&heap));
         }
+        heap.sort();
+        assert_eq!(sorted, heap);
     }
 
-    // Push forward to binary heap. Also may leave the heap as it is if the value is too small.
-    fn push_forward(&mut self, value: T) {
-        if self.data[0] > value {
+    // Changes one of the elements, and checks that the heap is restored.
+    fn check_update<const N: usize>(input: &[u8], index: usize, value: u8) {
+        let mut data = ArrayVec::<u8, N>::new();
+        input.iter().for_each(|&x| { push(&mut data, x, u8::cmp); });
+        if data.is_empty() {
             return;
         }
+        let index = index % data.len();
+        data[index] = value;
+        update(&mut data, index, u8::cmp);
+        assert!(is_heap(&data));
+        if N <= SMALL_N {
+            assert!(data.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
 
-        self.data[0] = value;
-        let mut index = 1;
-        while index * 2 < self.data.len() {
-            let left = index * 2;
-            let right = index * 2 + 1;
-            let mut j = left;
-            if right <= self.data.len() && self.data[left - 1] > self.data[right - 1] { 
-                j = right;
-            }
-            if self.data[index - 1] > self.data[j - 1] {
-                self.data.swap(index - 1, j - 1);
-                index = j;
-            } else {
-                break;
-            }
+    #[test]
+    fn test_only_left_child() {
+        let mut values = MaxValues::<i32, 2>::new();
+        values.push(1);
+        values.push(3);
+        values.push(7);
+        values.push(5);
+        assert_eq!(values.into_sorted_array().as_slice(), [7, 5]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_against_oracle(input in vec(0u8..16, 0..64)) {
+            check_against_oracle::<0>(&input);
+            check_against_oracle::<1>(&input);
+            check_against_oracle::<2>(&input);
+            check_against_oracle::<3>(&input);
+            check_against_oracle::<4>(&input);
+            check_against_oracle::<5>(&input);
+            check_against_oracle::<6>(&input);
+            check_against_oracle::<7>(&input);
+            check_against_oracle::<8>(&input);
+            check_against_oracle::<9>(&input);
+            check_against_oracle::<10>(&input);
+            check_against_oracle::<11>(&input);
+            check_against_oracle::<12>(&input);
+            check_against_oracle::<13>(&input);
+            check_against_oracle::<14>(&input);
+            check_against_oracle::<15>(&input);
+            check_against_oracle::<16>(&input);
+        }
+
+        #[test]
+        fn test_update(input in vec(0u8..16, 0..64), index in 0usize..64, value in 0u8..16) {
+            check_update::<1>(&input, index, value);
+            check_update::<4>(&input, index, value);
+            check_update::<8>(&input, index, value);
+            check_update::<9>(&input, index, value);
+            check_update::<16>(&input, index, value);
+            check_update::<32>(&input, index, value);
+        }
+
+        #[test]
+        fn test_small_against_heap(input in vec(0u8..16, 0..64)) {
+            check_small_against_heap::<1>(&input);
+            check_small_against_heap::<2>(&input);
+            check_small_against_heap::<3>(&input);
+            check_small_against_heap::<4>(&input);
+            check_small_against_heap::<5>(&input);
+            check_small_against_heap::<6>(&input);
+            check_small_against_heap::<7>(&input);
+            check_small_against_heap::<8>(&input);
         }
     }
 }
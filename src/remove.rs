@@ -0,0 +1,166 @@
+// Removal and update of the values of MaxValues, which keep the heap valid
+use core::fmt;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use arrayvec::ArrayVec;
+use crate::push::{heapify_array, update};
+use crate::MaxValues;
+
+impl<T: Ord, const N: usize> MaxValues<T, N> {
+    /// Retains only the values, for which `f` returns `true`, and removes the others.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let len = self.data.len();
+        self.data.retain(|x| f(x));
+        if self.data.len() != len {
+            heapify_array(&mut self.data, T::cmp);
+        }
+    }
+
+    /// Removes the values, for which `f` returns `true`, and returns them in no particular order.
+    pub fn remove_where(&mut self, mut f: impl FnMut(&T) -> bool) -> ArrayVec<T, N> {
+        let mut removed = ArrayVec::new();
+        let mut kept = ArrayVec::new();
+        for x in mem::take(&mut self.data) {
+            if f(&x) {
+                removed.push(x);
+            } else {
+                kept.push(x);
+            }
+        }
+        self.data = kept;
+        if !removed.is_empty() {
+            heapify_array(&mut self.data, T::cmp);
+        }
+        removed
+    }
+
+    /// Returns mutable handle to the smallest of the values, or `None` if self is empty.
+    /// The value can be changed through the handle, and the data structure is restored, when the handle is dropped.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, N>> {
+        if self.data.is_empty() {
+            None
+        } else {
+            Some(PeekMut { data: &mut self.data })
+        }
+    }
+}
+
+
+/// Mutable handle to the smallest value of [`MaxValues`], returned by [`MaxValues::peek_mut`]
+pub struct PeekMut<'a, T: Ord, const N: usize> {
+    data: &'a mut ArrayVec<T, N>
+}
+
+impl<T: Ord, const N: usize> PeekMut<'_, T, N> {
+    /// Removes the value from the data structure and returns it.
+    pub fn pop(this: Self) -> T {
+        let mut this = mem::ManuallyDrop::new(this);
+        let value = this.data.swap_remove(0);
+        if !this.data.is_empty() {
+            update(this.data, 0, T::cmp);
+        }
+        value
+    }
+}
+
+impl<T: Ord, const N: usize> Deref for PeekMut<'_, T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> DerefMut for PeekMut<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.data[0]
+    }
+}
+
+impl<T: Ord, const N: usize> Drop for PeekMut<'_, T, N> {
+    fn drop(&mut self) {
+        update(self.data, 0, T::cmp);
+    }
+}
+
+impl<T: Ord + fmt::Debug, const N: usize> fmt::Debug for PeekMut<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PeekMut").field(&self.data[0]).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::collection::vec;
+    use crate::{MaxValues, PeekMut};
+
+    #[test]
+    fn test_retain() {
+        let mut values = MaxValues::<i32, 4>::from_iter([5, 1, 8, 3, 9, 7]);
+        values.retain(|&x| x != 8);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [9, 7, 5]);
+        values.push(6);
+        values.push(2);
+        assert_eq!(values.into_sorted_array().as_slice(), [9, 7, 6, 5]);
+    }
+
+    #[test]
+    fn test_remove_where() {
+        let mut values = MaxValues::<i32, 16>::from_iter(0..20);
+        let mut removed = values.remove_where(|x| x % 3 == 0);
+        removed.sort();
+        assert_eq!(removed.as_slice(), [6, 9, 12, 15, 18]);
+        assert_eq!(values.len(), 11);
+        assert_eq!(values.peek_min(), Some(&4));
+        assert!(values.remove_where(|&x| x > 100).is_empty());
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut values = MaxValues::<i32, 3>::from_iter([4, 6, 2, 5]);
+        *values.peek_mut().unwrap() = 10;
+        assert_eq!(values.peek_min(), Some(&5));
+        let min = values.peek_mut().unwrap();
+        assert_eq!(PeekMut::pop(min), 5);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [10, 6]);
+        assert!(MaxValues::<i32, 0>::new().peek_mut().is_none());
+    }
+
+    // Checks, that the values stay the same as the sorted input after every change.
+    fn check_changes<const N: usize>(input: &[u8], removed: u8, added: u8) {
+        let mut values = MaxValues::<u8, N>::from_iter(input.iter().copied());
+        let mut expected = values.clone().into_ascending_array().to_vec();
+
+        values.retain(|&x| x != removed);
+        expected.retain(|&x| x != removed);
+        if let Some(mut min) = values.peek_mut() {
+            *min = added;
+            expected[0] = added;
+            expected.sort();
+        }
+        let mut other = values.clone();
+        if let Some(min) = other.peek_mut() {
+            PeekMut::pop(min);
+        }
+        assert_eq!(other.into_ascending_array().as_slice(), expected.get(1..).unwrap_or_default());
+        values.push(added);
+        expected.push(added);
+        expected.sort();
+        if expected.len() > N {
+            expected.remove(0);
+        }
+        assert_eq!(values.into_ascending_array().as_slice(), expected.as_slice());
+    }
+
+    proptest! {
+        #[test]
+        fn test_changes(input in vec(0u8..16, 0..64), removed in 0u8..16, added in 0u8..16) {
+            check_changes::<1>(&input, removed, added);
+            check_changes::<4>(&input, removed, added);
+            check_changes::<8>(&input, removed, added);
+            check_changes::<9>(&input, removed, added);
+            check_changes::<16>(&input, removed, added);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Serde support. MaxValues is serialized as a sequence of its values.
+use core::fmt;
+use core::marker::PhantomData;
+use arrayvec::ArrayVec;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use crate::MaxValues;
+
+impl<T: Ord + Serialize, const N: usize> Serialize for MaxValues<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.iter())
+    }
+}
+
+// Deserialized values may come in any order, so they are turned into the heap afterwards.
+impl<'de, T: Ord + Deserialize<'de>, const N: usize> Deserialize<'de> for MaxValues<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MaxValuesVisitor(PhantomData))
+    }
+}
+
+struct MaxValuesVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Ord + Deserialize<'de>, const N: usize> Visitor<'de> for MaxValuesVisitor<T, N> {
+    type Value = MaxValues<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of at most {} values", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut data = ArrayVec::new();
+        while let Some(value) = seq.next_element()? {
+            if data.try_push(value).is_err() {
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+        }
+        Ok(MaxValues::from_arrayvec(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaxValues;
+
+    #[test]
+    fn test_round_trip() {
+        let values = MaxValues::<i32, 4>::from_iter([3, 9, 1, 4, 12, 7]);
+        let json = serde_json::to_string(&values).unwrap();
+        let restored: MaxValues<i32, 4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.as_values(), values.as_values());
+    }
+
+    #[test]
+    fn test_reordered_payload() {
+        let mut values: MaxValues<i32, 4> = serde_json::from_str("[12, 9, 7, 4]").unwrap();
+        assert_eq!(values.peek_min(), Some(&4));
+        values.push(5);
+        values.push(1);
+        assert_eq!(values.into_sorted_array().as_slice(), [12, 9, 7, 5]);
+    }
+
+    #[test]
+    fn test_too_long_payload() {
+        assert!(serde_json::from_str::<MaxValues<i32, 2>>("[1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<MaxValues<i32, 2>>("[]").unwrap().is_empty());
+    }
+}
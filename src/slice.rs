@@ -0,0 +1,97 @@
+// Selection of max values out of slices, which are already in memory
+use arrayvec::ArrayVec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::push::{any_accepted, heapify, swap_forward, CHUNK_SIZE};
+use crate::MaxValues;
+
+// Quickselect makes several passes over the slice, while the heap makes only one and rejects most of the values
+// by comparing whole chunks with its smallest value, so the heap is faster if n is small comparing to the length.
+// In `benches/select.rs` the ratio of the length to n, at which quickselect becomes faster, grows with the length,
+// roughly as log2(len)^2, so quickselect is chosen once n reaches len / (3/4 * log2(len)^2).
+// The exact crossover depends on the machine and the type of the values.
+fn prefers_select(n: usize, len: usize) -> bool {
+    let log = len.checked_ilog2().unwrap_or(0) as usize;
+    n.saturating_mul(3 * log * log) >= len.saturating_mul(4)
+}
+
+/// Reorders given slice, so that its n biggest values go first, and returns them as a subslice.
+/// The subslice may be shorter than n, if the slice is shorter. Like [`MaxValues`], doesn't guarantee any order of the returned values.
+///
+/// Depending on the ratio of n to the length of the slice, uses either quickselect ([`slice::select_nth_unstable`]),
+/// or the binary heap, which is built in the beginning of the slice.
+pub fn top_n_in_place<T: Ord>(values: &mut [T], n: usize) -> &mut [T] {
+    if n >= values.len() {
+        return values;
+    }
+    if n == 0 {
+        return &mut [];
+    }
+
+    if prefers_select(n, values.len()) {
+        values.select_nth_unstable_by(n - 1, |a, b| b.cmp(a));
+    } else {
+        let (heap, rest) = values.split_at_mut(n);
+        heapify(heap, T::cmp);
+        for chunk in rest.chunks_mut(CHUNK_SIZE) {
+            if any_accepted(chunk, &heap[0]) {
+                chunk.iter_mut().for_each(|x| swap_forward(heap, x, T::cmp));
+            }
+        }
+    }
+    &mut values[..n]
+}
+
+impl<T: Ord + Clone, const N: usize> MaxValues<T, N> {
+    /// Creates [`MaxValues`] data structure, which contains N biggest values of given slice.
+    ///
+    /// Only the values, which may get into the data structure, are cloned. If N is big enough
+    /// comparing to the length of the slice and `alloc` feature is enabled, the slice is cloned
+    /// and its values are selected with quickselect instead (see [`top_n_in_place`]).
+    pub fn from_slice(values: &[T]) -> Self {
+        #[cfg(feature = "alloc")]
+        if values.len() > N && prefers_select(N, values.len()) {
+            let mut values: Vec<T> = values.to_vec();
+            top_n_in_place(&mut values, N);
+            values.truncate(N);
+            return MaxValues::from_arrayvec(values.into_iter().collect());
+        }
+
+        let split = values.len().min(N);
+        let mut result = MaxValues::from_arrayvec(values[..split].iter().cloned().collect::<ArrayVec<T, N>>());
+        result.push_slice(&values[split..]);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{top_n_in_place, MaxValues};
+
+    fn sorted_top(values: &[u32], n: usize) -> Vec<u32> {
+        let mut values = values.to_vec();
+        values.sort_by(|a, b| b.cmp(a));
+        values.truncate(n);
+        values
+    }
+
+    #[test]
+    fn test_top_n_in_place() {
+        let input: Vec<u32> = (0..1000u32).map(|x| x.wrapping_mul(2654435761) % 500).collect();
+        for n in [0, 1, 3, 10, 15, 16, 100, 999, 1000, 2000] {
+            let mut values = input.clone();
+            let mut top = top_n_in_place(&mut values, n).to_vec();
+            top.sort_by(|a, b| b.cmp(a));
+            assert_eq!(top, sorted_top(&input, n));
+        }
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let input: Vec<u32> = (0..1000u32).map(|x| x.wrapping_mul(2654435761) % 500).collect();
+        assert_eq!(MaxValues::<u32, 4>::from_slice(&input).into_sorted_array().to_vec(), sorted_top(&input, 4));
+        assert_eq!(MaxValues::<u32, 64>::from_slice(&input).into_sorted_array().to_vec(), sorted_top(&input, 64));
+        assert_eq!(MaxValues::<u32, 8>::from_slice(&input[..5]).into_sorted_array().to_vec(), sorted_top(&input[..5], 8));
+        assert!(MaxValues::<u32, 0>::from_slice(&input).is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+// Variant of MaxValues with configurable and reproducible handling of equal values
+use core::cmp::Ordering;
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+use crate::push::{heapify, push, sort_heap};
+use crate::Pushed;
+
+
+/// Defines which of equal values [`StableMaxValues`] keeps, when not all of them fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TiePolicy {
+    /// The values, which are pushed earlier, are kept.
+    #[default]
+    KeepFirst,
+    /// The values, which are pushed later, are kept, and the oldest of equal values is replaced first.
+    /// [`MaxValues`](crate::MaxValues) keeps the newest value too, but it's not specified, which of older equal values it replaces.
+    KeepLast,
+}
+
+impl TiePolicy {
+    // Comparison of the values with their sequence numbers: equal values are compared by
+    // the sequence numbers, so that the value, which has to be kept, is bigger.
+    fn compare<T: Ord>(self, a: &(T, u64), b: &(T, u64)) -> Ordering {
+        match self {
+            TiePolicy::KeepFirst => a.0.cmp(&b.0).then(b.1.cmp(&a.1)),
+            TiePolicy::KeepLast => a.0.cmp(&b.0).then(a.1.cmp(&b.1)),
+        }
+    }
+}
+
+
+/// Struct for getting max values out of given, which keeps equal values according to [`TiePolicy`].
+/// Every value is stored with its sequence number, so that sorted values are returned in the order they were pushed,
+/// if they are equal.
+#[derive(Debug, Clone)]
+pub struct StableMaxValues<T: Ord, const N: usize> {
+    data: ArrayVec<(T, u64), N>,
+    policy: TiePolicy,
+    sequence: u64
+}
+
+impl<T: Ord, const N: usize> StableMaxValues<T, N> {
+    /// Creates new empty [`StableMaxValues`] data structure, which keeps the first of equal values
+    pub fn new() -> Self {
+        Self::with_policy(TiePolicy::KeepFirst)
+    }
+
+    /// Creates new empty [`StableMaxValues`] data structure, which keeps equal values according to `policy`
+    pub fn with_policy(policy: TiePolicy) -> Self {
+        StableMaxValues { data: ArrayVec::new(), policy, sequence: 0 }
+    }
+
+    /// Returns the policy, which is used for equal values.
+    pub fn policy(&self) -> TiePolicy {
+        self.policy
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is bigger than one of the elements, or equal to it and the policy is [`TiePolicy::KeepLast`].
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        self.push_report(value);
+    }
+
+    /// Same as [`StableMaxValues::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the smallest element or is rejected.
+    pub fn push_report(&mut self, value: T) -> Pushed<T> {
+        let sequence = self.sequence;
+        self.sequence += 1;
+        let policy = self.policy;
+        match push(&mut self.data, (value, sequence), |a, b| policy.compare(a, b)) {
+            Pushed::Inserted => Pushed::Inserted,
+            Pushed::Replaced((old, _)) => Pushed::Replaced(old),
+            Pushed::Rejected((value, _)) => Pushed::Rejected(value),
+        }
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.data.iter().map(|(value, _)| value)
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns reference to the smallest of the values, which will be replaced first, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first().map(|(value, _)| value)
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values in no particular order.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.data.into_iter().map(|(value, _)| value).collect()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    /// Equal values are returned in the order they were pushed.
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        let mut data = self.data;
+        // Sorting by the heap order puts the kept values first, which is the push order only for KeepFirst
+        let order = TiePolicy::KeepFirst;
+        if self.policy != order {
+            heapify(&mut data, |a, b| order.compare(a, b));
+        }
+        sort_heap(&mut data, |a, b| order.compare(a, b));
+        data.into_iter().map(|(value, _)| value).collect()
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    /// Equal values are returned in the order they were pushed.
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+}
+
+impl<T: Ord, const N: usize> Default for StableMaxValues<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for StableMaxValues<T, N> {
+    fn from_iter<IterT: IntoIterator<Item = T>>(iter: IterT) -> Self {
+        let mut values = StableMaxValues::new();
+        iter.into_iter().for_each(|x| values.push(x));
+        values
+    }
+}
+
+impl<T: Ord, const N: usize> Extend<T> for StableMaxValues<T, N> {
+    fn extend<IterT: IntoIterator<Item = T>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use crate::{StableMaxValues, TiePolicy};
+
+    // Value, which is compared only by score, so that equal values can be told apart by id
+    #[derive(Debug, Clone, Copy)]
+    struct Item {
+        score: u32,
+        id: char,
+    }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.score == other.score
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.score.cmp(&other.score)
+        }
+    }
+
+    fn items() -> [Item; 7] {
+        [(3, 'a'), (5, 'b'), (3, 'c'), (1, 'd'), (3, 'e'), (7, 'f'), (3, 'g')]
+            .map(|(score, id)| Item { score, id })
+    }
+
+    fn ids<const N: usize>(values: StableMaxValues<Item, N>) -> String {
+        values.into_sorted_iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn test_keep_first() {
+        assert_eq!(ids(StableMaxValues::<Item, 4>::from_iter(items())), "fbac");
+        assert_eq!(ids(StableMaxValues::<Item, 12>::from_iter(items())), "fbacegd");
+    }
+
+    #[test]
+    fn test_keep_last() {
+        let mut values = StableMaxValues::<Item, 4>::with_policy(TiePolicy::KeepLast);
+        values.extend(items());
+        assert_eq!(ids(values), "fbeg");
+
+        let mut values = StableMaxValues::<Item, 12>::with_policy(TiePolicy::KeepLast);
+        values.extend(items());
+        assert_eq!(ids(values), "fbacegd");
+    }
+}
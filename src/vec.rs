@@ -0,0 +1,203 @@
+// Variant of MaxValues, which capacity is chosen at runtime
+use core::slice::Iter;
+use alloc::vec::Vec;
+use alloc::vec::IntoIter;
+use crate::push::{push_vec, sort_heap};
+use crate::Pushed;
+
+
+/// Struct for getting max values out of given, which stores the values in [`Vec`].
+/// Unlike [`MaxValues`](crate::MaxValues), the number of values is chosen at runtime.
+#[derive(Debug, Clone)]
+pub struct MaxValuesVec<T: Ord> {
+    data: Vec<T>,
+    capacity: usize
+}
+
+impl<T: Ord> MaxValuesVec<T> {
+    /// Creates new empty [`MaxValuesVec`] data structure, which keeps at most `capacity` values.
+    /// Doesn't allocate memory up front: the values are allocated as they are pushed,
+    /// so that `capacity` may be much bigger than the number of pushed values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        MaxValuesVec { data: Vec::new(), capacity }
+    }
+
+    /// Creates [`MaxValuesVec`] data structure, which keeps at most `capacity` max values of given iterator.
+    /// Allocates memory for as many values, as the iterator is known to yield, but not more than `capacity`.
+    pub fn from_iter_with_capacity<IterT: IntoIterator<Item = T>>(capacity: usize, iter: IterT) -> Self {
+        let iter = iter.into_iter();
+        let mut values = MaxValuesVec { data: Vec::with_capacity(capacity.min(iter.size_hint().0)), capacity };
+        iter.for_each(|x| values.push(x));
+        values
+    }
+
+    /// Pushes an element into the data structure,
+    /// if it is bigger than one of the elements.
+    /// May replace one of the previously pushed elements.
+    pub fn push(&mut self, value: T) {
+        self.push_report(value);
+    }
+
+    /// Same as [`MaxValuesVec::push`], but returns what happened to the data structure:
+    /// whether the element is inserted, replaced the smallest element or is rejected.
+    pub fn push_report(&mut self, value: T) -> Pushed<T> {
+        push_vec(&mut self.data, self.capacity, value, T::cmp)
+    }
+
+    /// Consumes self and returns [`Vec`] of the values. Note, that returned vector may contain less than `capacity` values, if less values are pushed to MaxValuesVec.
+    pub fn to_values(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns immutable slice of the values. Note, that returned slice may contain less than `capacity` values, if less values are pushed to MaxValuesVec.
+    /// The reason why there is no mutable version of this method is that mutating the elements can invalidate the binary heap used by this data structure.
+    pub fn as_values(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns an iterator over the values of self.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no values are pushed to self.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if self contains `capacity` values, so that every next push replaces one of them or is rejected.
+    pub fn is_full(&self) -> bool {
+        self.data.len() == self.capacity
+    }
+
+    /// Returns the maximum number of values, which self can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns reference to the smallest of the values, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns reference to the smallest of the values only if self is full.
+    /// Values, which are smaller than the threshold, will be rejected by [`MaxValuesVec::push`].
+    pub fn threshold(&self) -> Option<&T> {
+        if self.is_full() {
+            self.peek_min()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if pushing given value would change self.
+    pub fn would_accept(&self, value: &T) -> bool {
+        match self.threshold() {
+            Some(min) => min <= value,
+            None => !self.is_full(),
+        }
+    }
+
+    /// Consumes self and returns [`Vec`] of the values, sorted in descending order (the biggest value goes first).
+    /// The values are sorted in place, without any additional allocation.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut data = self.data;
+        sort_heap(&mut data, T::cmp);
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T> {
+        self.into_sorted_vec().into_iter()
+    }
+
+    /// Consumes self and returns [`Vec`] of the values, sorted in ascending order (the smallest value goes first).
+    pub fn into_ascending_vec(self) -> Vec<T> {
+        let mut data = self.into_sorted_vec();
+        data.reverse();
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in ascending order (the smallest value goes first).
+    pub fn into_ascending_iter(self) -> IntoIter<T> {
+        self.into_ascending_vec().into_iter()
+    }
+}
+
+impl<T: Ord> AsRef<[T]> for MaxValuesVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Ord> IntoIterator for MaxValuesVec<T> {
+    type IntoIter = IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MaxValues, MaxValuesVec, MaxValuesIterExt, Pushed};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_max_values_vec() {
+        let mut values = MaxValuesVec::with_capacity(3);
+        values.push(2);
+        assert_eq!(values.as_ref(), [2]);
+
+        values.push(3);
+        values.push(4);
+        values.push(1);
+        assert_eq!(values.iter().copied().collect::<HashSet<_>>(), HashSet::from([2, 3, 4]));
+        assert_eq!(values.threshold(), Some(&2));
+
+        values.push(5);
+        assert_eq!(values.into_sorted_vec(), [5, 4, 3]);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut values = MaxValuesVec::with_capacity(0);
+        assert_eq!(values.push_report(1), Pushed::Rejected(1));
+        assert!(values.is_empty());
+        assert_eq!([1, 2, 3].into_iter().max_values_dyn(0).next(), None);
+    }
+
+    #[test]
+    fn test_huge_capacity() {
+        let mut values = MaxValuesVec::with_capacity(usize::MAX);
+        values.push(1u32);
+        assert_eq!(values.capacity(), usize::MAX);
+        assert!(!values.is_full());
+        assert_eq!([1u32, 2, 3].into_iter().max_values_dyn(usize::MAX).collect::<Vec<_>>().len(), 3);
+        assert_eq!(MaxValuesVec::from_iter_with_capacity(1_000_000_000, [3u32, 1, 2]).into_sorted_vec(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_same_as_max_values() {
+        let arr = [4, 8, 1, 9, 9, 0, 3, 7, 5, 2, 6];
+        assert_eq!(
+            MaxValuesVec::from_iter_with_capacity(6, arr).into_ascending_vec().as_slice(),
+            MaxValues::<i32, 6>::from_iter(arr).into_ascending_array().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_iterator() {
+        let values = [1, 5, 2, 4, 7, 10, 0, 15, 3];
+        assert_eq!(
+            values.into_iter().max_values_dyn(3).collect::<HashSet<_>>(),
+            HashSet::from([7, 10, 15])
+        );
+    }
+}
@@ -0,0 +1,242 @@
+// Variant of MaxValues, which forgets the values, that are out of the sliding window
+use alloc::collections::{BTreeSet, VecDeque};
+use arrayvec::IntoIter;
+use arrayvec::ArrayVec;
+
+
+/// Defines which of the pushed values are in the window of [`WindowedMaxValues`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    /// Given number of the last pushed values.
+    Count(usize),
+    /// The values, which timestamps are less than given span before the timestamp of the last pushed value.
+    Span(u64),
+}
+
+
+/// Struct for getting max values out of the sliding window of given values, see [`Window`].
+///
+/// Every value in the window is stored, since any of them may become one of max values once bigger values expire.
+/// The values are kept both in the order they were pushed and in the ordered set, whose last N elements are max values,
+/// so that pushing and expiring a value take O(log W) time, where W is the size of the window.
+/// That's why pushing requires the values to implement [`Clone`].
+#[derive(Debug, Clone)]
+pub struct WindowedMaxValues<T: Ord, const N: usize> {
+    // Values of the window with their timestamps, the oldest goes first
+    elements: VecDeque<(u64, T)>,
+    // Values of the window with their sequence numbers. Equal values are compared by their sequence numbers,
+    // so that the newer values, which expire later, are kept
+    index: BTreeSet<(T, u64)>,
+    // Sequence number of the first element of the window
+    first: u64,
+    window: Window,
+    timestamp: u64
+}
+
+impl<T: Ord, const N: usize> WindowedMaxValues<T, N> {
+    /// Creates new empty [`WindowedMaxValues`] data structure, which keeps max values of given `window`
+    pub fn new(window: Window) -> Self {
+        WindowedMaxValues { elements: VecDeque::new(), index: BTreeSet::new(), first: 0, window, timestamp: 0 }
+    }
+
+    /// Returns the window of self.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Expires the elements, which are out of the window at given time, without pushing anything.
+    pub fn expire(&mut self, now: u64) {
+        self.timestamp = self.timestamp.max(now);
+        let expired = match self.window {
+            Window::Count(count) => self.elements.len().saturating_sub(count),
+            Window::Span(span) => self.elements.iter()
+                .take_while(|(timestamp, _)| timestamp.saturating_add(span) <= self.timestamp)
+                .count(),
+        };
+        for (_, value) in self.elements.drain(..expired) {
+            self.index.remove(&(value, self.first));
+            self.first += 1;
+        }
+    }
+
+    /// Returns an iterator over the values of self in descending order (the biggest value goes first).
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.index.iter().rev().take(N).map(|(value, _)| value)
+    }
+
+    /// Returns the number of values in self.
+    pub fn len(&self) -> usize {
+        self.index.len().min(N)
+    }
+
+    /// Returns `true` if there are no values in the window.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of the values in the window, including the ones, which aren't max values.
+    pub fn window_len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns reference to the smallest of the values, or `None` if self is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values in no particular order.
+    pub fn to_values(self) -> ArrayVec<T, N> {
+        self.into_sorted_array()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in descending order (the biggest value goes first).
+    pub fn into_sorted_array(self) -> ArrayVec<T, N> {
+        self.index.into_iter().rev().take(N).map(|(value, _)| value).collect()
+    }
+
+    /// Consumes self and returns an iterator over the values in descending order (the biggest value goes first).
+    pub fn into_sorted_iter(self) -> IntoIter<T, N> {
+        self.into_sorted_array().into_iter()
+    }
+
+    /// Consumes self and returns [`ArrayVec`] of the values, sorted in ascending order (the smallest value goes first).
+    pub fn into_ascending_array(self) -> ArrayVec<T, N> {
+        let mut data = self.into_sorted_array();
+        data.reverse();
+        data
+    }
+
+    /// Consumes self and returns an iterator over the values in ascending order (the smallest value goes first).
+    pub fn into_ascending_iter(self) -> IntoIter<T, N> {
+        self.into_ascending_array().into_iter()
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> WindowedMaxValues<T, N> {
+    /// Pushes an element with the same timestamp as the last pushed element (or zero, if nothing is pushed),
+    /// and expires the elements, which are out of the window. Mostly useful for [`Window::Count`].
+    pub fn push(&mut self, value: T) {
+        self.push_at(self.timestamp, value);
+    }
+
+    /// Pushes an element with given timestamp, and expires the elements, which are out of the window.
+    /// The timestamps are expected to be non-decreasing: the timestamp, which is less than the last one, is treated as equal to it.
+    pub fn push_at(&mut self, timestamp: u64, value: T) {
+        self.timestamp = self.timestamp.max(timestamp);
+        let sequence = self.first + self.elements.len() as u64;
+        self.elements.push_back((self.timestamp, value.clone()));
+        self.index.insert((value, sequence));
+        self.expire(self.timestamp);
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> Extend<T> for WindowedMaxValues<T, N> {
+    fn extend<IterT: IntoIterator<Item = T>>(&mut self, iter: IterT) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::collection::vec;
+    use crate::{Window, WindowedMaxValues};
+
+    #[test]
+    fn test_count() {
+        let mut values = WindowedMaxValues::<u32, 2>::new(Window::Count(4));
+        values.extend([9, 1, 8, 2]);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [9, 8]);
+        assert_eq!(values.peek_min(), Some(&8));
+
+        values.push(3);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [8, 3]);
+        values.push(0);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [8, 3]);
+        values.push(1);
+        assert_eq!(values.window_len(), 4);
+        assert_eq!(values.into_ascending_array().as_slice(), [2, 3]);
+    }
+
+    #[test]
+    fn test_span() {
+        let mut values = WindowedMaxValues::<u32, 2>::new(Window::Span(10));
+        values.push_at(0, 50);
+        values.push_at(3, 20);
+        values.push_at(7, 40);
+        values.push_at(9, 10);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [50, 40]);
+
+        values.push_at(10, 5);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [40, 20]);
+        values.expire(17);
+        assert_eq!(values.clone().into_sorted_array().as_slice(), [10, 5]);
+        values.expire(100);
+        assert!(values.is_empty());
+        assert_eq!(values.window_len(), 0);
+    }
+
+    #[test]
+    fn test_equal_values() {
+        let mut values = WindowedMaxValues::<u32, 1>::new(Window::Count(2));
+        values.extend([7, 7, 1]);
+        assert_eq!(values.iter().copied().collect::<Vec<_>>(), [7]);
+        values.push(1);
+        assert_eq!(values.to_values().as_slice(), [1]);
+    }
+
+    // Compares WindowedMaxValues with sorting the last values of the input after every push.
+    fn check_against_oracle<const N: usize>(input: &[u8], count: usize) {
+        let mut values = WindowedMaxValues::<u8, N>::new(Window::Count(count));
+        for (i, &x) in input.iter().enumerate() {
+            values.push(x);
+            let mut expected = input[(i + 1).saturating_sub(count)..=i].to_vec();
+            expected.sort_by(|a, b| b.cmp(a));
+            expected.truncate(N);
+            assert_eq!(values.clone().into_sorted_array().as_slice(), expected.as_slice());
+        }
+    }
+
+    // Compares WindowedMaxValues with sorting the values of the input, which are not older than the span, after every push.
+    // The input contains increments of the timestamps along with the values.
+    fn check_span_against_oracle<const N: usize>(input: &[(u64, u8)], span: u64) {
+        let mut values = WindowedMaxValues::<u8, N>::new(Window::Span(span));
+        let mut pushed = Vec::new();
+        let mut now = 0;
+        for &(increment, x) in input {
+            now += increment;
+            values.push_at(now, x);
+            pushed.push((now, x));
+            let mut expected = pushed.iter()
+                .filter(|(timestamp, _)| timestamp + span > now)
+                .map(|&(_, x)| x)
+                .collect::<Vec<_>>();
+            expected.sort_by(|a, b| b.cmp(a));
+            expected.truncate(N);
+            assert_eq!(values.iter().copied().collect::<Vec<_>>(), expected);
+            assert_eq!(values.clone().into_sorted_array().as_slice(), expected.as_slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_against_oracle(input in vec(0u8..16, 0..64), count in 0usize..16) {
+            check_against_oracle::<0>(&input, count);
+            check_against_oracle::<1>(&input, count);
+            check_against_oracle::<3>(&input, count);
+            check_against_oracle::<8>(&input, count);
+            check_against_oracle::<9>(&input, count);
+            check_against_oracle::<16>(&input, count);
+        }
+
+        #[test]
+        fn test_span_against_oracle(input in vec((0u64..4, 0u8..16), 0..64), span in 0u64..16) {
+            check_span_against_oracle::<0>(&input, span);
+            check_span_against_oracle::<1>(&input, span);
+            check_span_against_oracle::<3>(&input, span);
+            check_span_against_oracle::<8>(&input, span);
+            check_span_against_oracle::<9>(&input, span);
+            check_span_against_oracle::<16>(&input, span);
+        }
+    }
+}